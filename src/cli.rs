@@ -1,9 +1,15 @@
-use clap::{Args, Parser, Subcommand};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
 #[derive(Debug, Parser)]
 #[command(name = "brel", version, about = "better-releases workflow setup tool")]
 pub struct Cli {
+    /// Print each release step (baseline tag, commits, bump level, changed files, commands) to stderr.
+    #[arg(long, global = true)]
+    pub verbose: bool,
+    /// Suppress informational status lines on stdout; warnings, errors, and command output remain.
+    #[arg(long, global = true)]
+    pub quiet: bool,
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -16,6 +22,14 @@ pub enum Commands {
     ReleasePr(ReleasePrArgs),
     /// Compute the next releasable version.
     NextVersion(NextVersionArgs),
+    /// Query resolved configuration values.
+    Config(ConfigArgs),
+    /// Detect version files and propose `release_pr.version_updates` entries.
+    Detect(DetectArgs),
+    /// Create the release tag for the just-merged release at HEAD.
+    Tag(TagArgs),
+    /// Show the latest release, the next version, and the open release PR without changing anything.
+    Status(StatusArgs),
 }
 
 #[derive(Debug, Args, Clone)]
@@ -23,12 +37,46 @@ pub struct InitArgs {
     /// Path to a config file. Defaults to brel.toml, then .brel.toml in current directory.
     #[arg(long)]
     pub config: Option<PathBuf>,
+    /// Merge `[profile.<NAME>]` from the config file over the base settings.
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
     /// Run non-interactively and auto-confirm overwrite prompts.
     #[arg(long)]
     pub yes: bool,
     /// Show what would change without writing files.
     #[arg(long)]
     pub dry_run: bool,
+    /// With `--yes`, replace an existing workflow file even if brel does not manage it.
+    #[arg(long, requires = "yes")]
+    pub force: bool,
+    /// How `--dry-run` reports the planned change.
+    #[arg(long, value_enum, default_value_t, requires = "dry_run")]
+    pub format: OutputFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// A status line and a unified diff.
+    #[default]
+    Human,
+    /// A single JSON object with `path`, `action`, and `diff`.
+    Json,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct DetectArgs {
+    /// Path to a config file. Defaults to brel.toml, then .brel.toml in current directory.
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+    /// Merge `[profile.<NAME>]` from the config file over the base settings.
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+    /// Run non-interactively and write detected entries without prompting.
+    #[arg(long)]
+    pub yes: bool,
+    /// Print detected entries without writing the config file.
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
 #[derive(Debug, Args, Clone)]
@@ -36,6 +84,36 @@ pub struct ReleasePrArgs {
     /// Path to a config file. Defaults to brel.toml, then .brel.toml in current directory.
     #[arg(long)]
     pub config: Option<PathBuf>,
+    /// Merge `[profile.<NAME>]` from the config file over the base settings.
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+    /// Target branch for the release PR. Overrides `default_branch` and only considers tags reachable from it.
+    #[arg(long)]
+    pub base: Option<String>,
+    /// Show what would change without writing files, committing, pushing, or calling `gh`.
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Release this version (for example the output of `brel next-version`) instead of rescanning commits.
+    #[arg(long, value_name = "VERSION")]
+    pub reuse_version: Option<semver::Version>,
+    /// Promote the latest prerelease tag (for example `1.3.0-rc.3`) to its stable version, ignoring new commits.
+    #[arg(long, conflicts_with = "reuse_version")]
+    pub graduate: bool,
+    /// Read the GitHub token from this file when `GH_TOKEN`/`GITHUB_TOKEN` are not set.
+    #[arg(long, value_name = "PATH")]
+    pub token_file: Option<PathBuf>,
+    /// Create the release branch and commit locally, then stop without pushing or calling GitHub.
+    #[arg(long, conflicts_with = "dry_run")]
+    pub local: bool,
+    /// When no GitHub token can be found, fall back to `--local` instead of failing (for forks).
+    #[arg(long, conflicts_with = "dry_run")]
+    pub allow_no_token: bool,
+    /// Fail instead of warning when a release is due but `release_pr.version_updates` is empty.
+    #[arg(long)]
+    pub strict: bool,
+    /// Overwrite the release branch even when it has commits from someone other than the release author.
+    #[arg(long, conflicts_with = "dry_run")]
+    pub force: bool,
 }
 
 #[derive(Debug, Args, Clone)]
@@ -43,4 +121,69 @@ pub struct NextVersionArgs {
     /// Path to a config file. Defaults to brel.toml, then .brel.toml in current directory.
     #[arg(long)]
     pub config: Option<PathBuf>,
+    /// Merge `[profile.<NAME>]` from the config file over the base settings.
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+    /// Print the number of releasable commits next to the version.
+    #[arg(long)]
+    pub count: bool,
+    /// Print the release tag the next version is computed from instead (empty on the first release).
+    #[arg(long, conflicts_with = "count")]
+    pub previous_tag: bool,
+    /// Start of the commit range. Defaults to the latest release tag.
+    #[arg(long)]
+    pub from: Option<String>,
+    /// End of the commit range. Defaults to HEAD.
+    #[arg(long)]
+    pub to: Option<String>,
+    /// Baseline version to bump. Required when `--from` is not a release tag.
+    #[arg(long, requires = "from")]
+    pub base_version: Option<semver::Version>,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct TagArgs {
+    /// Path to a config file. Defaults to brel.toml, then .brel.toml in current directory.
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+    /// Merge `[profile.<NAME>]` from the config file over the base settings.
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+    /// Push the new tag to `origin` after creating it.
+    #[arg(long)]
+    pub push: bool,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct StatusArgs {
+    /// Path to a config file. Defaults to brel.toml, then .brel.toml in current directory.
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+    /// Merge `[profile.<NAME>]` from the config file over the base settings.
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub command: ConfigCommands,
+}
+
+#[derive(Debug, Subcommand, Clone)]
+pub enum ConfigCommands {
+    /// Print a single resolved config value.
+    Get(ConfigGetArgs),
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct ConfigGetArgs {
+    /// Dotted config key, for example `release_pr.changelog.output_file`.
+    pub key: String,
+    /// Path to a config file. Defaults to brel.toml, then .brel.toml in current directory.
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+    /// Merge `[profile.<NAME>]` from the config file over the base settings.
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
 }
@@ -1,3 +1,5 @@
+use crate::error::BrelError;
+use crate::path_glob;
 use crate::tag_template;
 use crate::version_selector;
 use anyhow::{Context, Result, bail};
@@ -11,10 +13,15 @@ use std::str::FromStr;
 pub const DEFAULT_BRANCH: &str = "main";
 pub const DEFAULT_WORKFLOW_FILE: &str = "release-pr.yml";
 pub const DEFAULT_RELEASE_BRANCH_PATTERN: &str = "brel/release/v{{version}}";
+pub const DEFAULT_PRERELEASE_BRANCH_PATTERN: &str = "brel/prerelease/v{{version}}";
 pub const DEFAULT_COMMIT_AUTHOR_NAME: &str = "brel[bot]";
 pub const DEFAULT_COMMIT_AUTHOR_EMAIL: &str = "brel[bot]@users.noreply.github.com";
 pub const DEFAULT_CHANGELOG_OUTPUT_FILE: &str = "CHANGELOG.md";
 pub const DEFAULT_TAGGING_ENABLED: bool = false;
+pub const DEFAULT_RUN_HOOKS: bool = false;
+pub const DEFAULT_LOCK_TIMEOUT_SECS: u64 = 1800;
+pub const DEFAULT_MANAGED_MARKER: &str = "managed-by: brel";
+pub const DEFAULT_ISSUE_PATTERN: &str = r"(?:#|GH-)(\d+)\b";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Provider {
@@ -56,6 +63,7 @@ impl FromStr for Provider {
 pub enum VersionFileFormat {
     Json,
     Toml,
+    Anchored,
 }
 
 impl VersionFileFormat {
@@ -63,6 +71,7 @@ impl VersionFileFormat {
         match self {
             Self::Json => "json",
             Self::Toml => "toml",
+            Self::Anchored => "anchored",
         }
     }
 }
@@ -80,11 +89,213 @@ impl FromStr for VersionFileFormat {
         match value.trim().to_ascii_lowercase().as_str() {
             "json" => Ok(Self::Json),
             "toml" => Ok(Self::Toml),
-            other => bail!("Unsupported format override `{other}`. Expected `json` or `toml`."),
+            "anchored" => Ok(Self::Anchored),
+            other => bail!(
+                "Unsupported format override `{other}`. Expected `json`, `toml`, or `anchored`."
+            ),
         }
     }
 }
 
+/// Per-selector formats from `{ selector, format }` entries in `version_updates`, keyed by the
+/// configured path and then the selector.
+pub type SelectorFormats = BTreeMap<String, BTreeMap<String, VersionFileFormat>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BumpLevel {
+    Patch,
+    Minor,
+    Major,
+}
+
+impl BumpLevel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Patch => "patch",
+            Self::Minor => "minor",
+            Self::Major => "major",
+        }
+    }
+}
+
+impl fmt::Display for BumpLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str((*self).as_str())
+    }
+}
+
+impl FromStr for BumpLevel {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "patch" => Ok(Self::Patch),
+            "minor" => Ok(Self::Minor),
+            "major" => Ok(Self::Major),
+            other => {
+                bail!("Unsupported bump level `{other}`. Expected `patch`, `minor`, or `major`.")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NonUtf8CommitPolicy {
+    #[default]
+    Keep,
+    Skip,
+}
+
+impl NonUtf8CommitPolicy {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Keep => "keep",
+            Self::Skip => "skip",
+        }
+    }
+}
+
+impl fmt::Display for NonUtf8CommitPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str((*self).as_str())
+    }
+}
+
+impl FromStr for NonUtf8CommitPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "keep" => Ok(Self::Keep),
+            "skip" => Ok(Self::Skip),
+            other => {
+                bail!("Unsupported non-UTF-8 commit policy `{other}`. Expected `keep` or `skip`.")
+            }
+        }
+    }
+}
+
+/// How `brel release-pr` publishes the release commit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReleaseMode {
+    /// Push a release branch and open or update a release PR.
+    #[default]
+    Pr,
+    /// Commit straight onto the base branch and push it, bypassing review.
+    Direct,
+}
+
+impl ReleaseMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pr => "pr",
+            Self::Direct => "direct",
+        }
+    }
+}
+
+impl fmt::Display for ReleaseMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str((*self).as_str())
+    }
+}
+
+impl FromStr for ReleaseMode {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "pr" => Ok(Self::Pr),
+            "direct" => Ok(Self::Direct),
+            other => bail!("Unsupported release mode `{other}`. Expected `pr` or `direct`."),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CommitFormat {
+    /// `type(scope)!: subject`
+    #[default]
+    Conventional,
+    /// `[type(scope)!] subject`
+    Bracketed,
+}
+
+impl CommitFormat {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Conventional => "conventional",
+            Self::Bracketed => "bracketed",
+        }
+    }
+}
+
+impl fmt::Display for CommitFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str((*self).as_str())
+    }
+}
+
+impl FromStr for CommitFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "conventional" => Ok(Self::Conventional),
+            "bracketed" => Ok(Self::Bracketed),
+            other => bail!(
+                "Unsupported commit format `{other}`. Expected `conventional` or `bracketed`."
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum VersionSource {
+    #[default]
+    Tags,
+    File {
+        path: String,
+        selector: String,
+    },
+}
+
+impl fmt::Display for VersionSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tags => f.write_str("tags"),
+            Self::File { path, selector } => write!(f, "file:{path}:{selector}"),
+        }
+    }
+}
+
+impl FromStr for VersionSource {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        if value.eq_ignore_ascii_case("tags") {
+            return Ok(Self::Tags);
+        }
+        // Selectors never contain `:`, so the last one separates it from the path.
+        let Some((path, selector)) = value
+            .strip_prefix("file:")
+            .and_then(|rest| rest.rsplit_once(':'))
+            .map(|(path, selector)| (path.trim(), selector.trim()))
+            .filter(|(path, selector)| !path.is_empty() && !selector.is_empty())
+        else {
+            bail!(
+                "Unsupported version source `{value}`. Expected `tags` or \
+                 `file:<path>:<selector>`."
+            );
+        };
+        Ok(Self::File {
+            path: path.to_string(),
+            selector: selector.to_string(),
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ConfigSource {
     Explicit(PathBuf),
@@ -99,6 +310,15 @@ impl ConfigSource {
             Self::Defaulted => None,
         }
     }
+
+    // Discovered configs may live in a parent of the working directory; their directory is
+    // the root that `version_updates` paths are relative to. Explicit paths keep `cwd`.
+    pub fn root_dir<'a>(&'a self, cwd: &'a Path) -> &'a Path {
+        match self {
+            Self::Discovered(path) => path.parent().unwrap_or(cwd),
+            Self::Explicit(_) | Self::Defaulted => cwd,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -111,32 +331,138 @@ pub struct CommitAuthorConfig {
 pub struct ChangelogConfig {
     pub enabled: bool,
     pub output_file: String,
+    pub since_previous_tag: bool,
+    pub config_file: Option<String>,
+    /// Commit types listed in the PR body and release notes; empty lists every commit.
+    pub include_types: Vec<String>,
+    /// Tag passed to git-cliff for the changelog heading; `None` uses `tagging.tag_template`.
+    pub tag_template: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GithubConfig {
+    pub use_api: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TaggingConfig {
     pub enabled: bool,
     pub tag_template: String,
+    pub annotated: bool,
+    pub sign: bool,
+    pub message_template: String,
+    pub require_annotated_for_baseline: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseGroupConfig {
+    pub scopes: Vec<String>,
+    pub version_updates: BTreeMap<String, Vec<String>>,
+    pub selector_formats: SelectorFormats,
+    pub tag_template: String,
+    pub release_branch_pattern: String,
+    pub prerelease_branch_pattern: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ReleasePrConfig {
     pub version_updates: BTreeMap<String, Vec<String>>,
+    pub selector_formats: SelectorFormats,
     pub format_overrides: BTreeMap<String, VersionFileFormat>,
+    pub tag_files: Vec<String>,
+    pub allow_empty_globs: bool,
+    pub allow_missing_selectors: bool,
+    pub strict_single_match: bool,
+    pub exclude: Vec<String>,
     pub release_branch_pattern: String,
+    pub prerelease_branch_pattern: String,
+    pub release_branch_slugify: bool,
+    pub cleanup_stale_branches: bool,
     pub pr_template_file: Option<String>,
+    pub pr_title_template: Option<String>,
+    pub milestone: Option<String>,
+    pub notes_output_file: Option<String>,
+    pub post_update_command: Option<String>,
+    /// Paragraph between the release commit subject and its trailers; supports `{version}`/`{tag}`.
+    pub commit_body_template: Option<String>,
+    /// `Key: value` trailers appended to the release commit message.
+    pub commit_trailers: Vec<String>,
+    /// Adds a `Signed-off-by` trailer for the release commit author.
+    pub commit_signoff: bool,
+    pub run_hooks: bool,
+    pub all_tags: bool,
+    pub include_prereleases: bool,
+    pub lock: bool,
+    pub lock_timeout_secs: u64,
+    pub release_as_footer: Option<String>,
+    pub build_metadata_template: Option<String>,
+    pub minimum_bump: Option<BumpLevel>,
+    pub release_threshold: Option<BumpLevel>,
+    pub trailer_bump: bool,
+    /// Level a breaking change bumps on its own; the commit type can still raise it.
+    pub breaking_change_as: BumpLevel,
+    pub ignore_merges: bool,
+    pub commit_format: CommitFormat,
+    pub non_utf8_commits: NonUtf8CommitPolicy,
+    pub mode: ReleaseMode,
+    pub managed_marker: String,
+    pub version_source: VersionSource,
+    pub issue_pattern: String,
+    /// Caps the commits listed in the PR body; the rest are summarized as one line.
+    pub max_body_commits: Option<usize>,
+    pub type_aliases: BTreeMap<String, String>,
     pub commit_author: CommitAuthorConfig,
     pub changelog: ChangelogConfig,
+    pub github: GithubConfig,
     pub tagging: TaggingConfig,
+    pub groups: BTreeMap<String, ReleaseGroupConfig>,
+    /// Commit scopes that count toward the release; empty means every commit. Set per group.
+    pub scopes: Vec<String>,
 }
 
 impl Default for ReleasePrConfig {
     fn default() -> Self {
         Self {
             version_updates: BTreeMap::new(),
+            selector_formats: BTreeMap::new(),
             format_overrides: BTreeMap::new(),
+            tag_files: Vec::new(),
+            allow_empty_globs: false,
+            allow_missing_selectors: false,
+            strict_single_match: false,
+            exclude: Vec::new(),
             release_branch_pattern: DEFAULT_RELEASE_BRANCH_PATTERN.to_string(),
+            prerelease_branch_pattern: DEFAULT_PRERELEASE_BRANCH_PATTERN.to_string(),
+            release_branch_slugify: false,
+            cleanup_stale_branches: false,
             pr_template_file: None,
+            pr_title_template: None,
+            milestone: None,
+            notes_output_file: None,
+            post_update_command: None,
+            commit_body_template: None,
+            commit_trailers: Vec::new(),
+            commit_signoff: false,
+            run_hooks: DEFAULT_RUN_HOOKS,
+            all_tags: false,
+            include_prereleases: false,
+            lock: false,
+            lock_timeout_secs: DEFAULT_LOCK_TIMEOUT_SECS,
+            release_as_footer: None,
+            build_metadata_template: None,
+            minimum_bump: None,
+            release_threshold: None,
+            trailer_bump: false,
+            breaking_change_as: BumpLevel::Major,
+            ignore_merges: true,
+            commit_format: CommitFormat::Conventional,
+            non_utf8_commits: NonUtf8CommitPolicy::Keep,
+            mode: ReleaseMode::Pr,
+            managed_marker: DEFAULT_MANAGED_MARKER.to_string(),
+            version_source: VersionSource::Tags,
+            issue_pattern: DEFAULT_ISSUE_PATTERN.to_string(),
+            max_body_commits: None,
+            type_aliases: BTreeMap::new(),
             commit_author: CommitAuthorConfig {
                 name: DEFAULT_COMMIT_AUTHOR_NAME.to_string(),
                 email: DEFAULT_COMMIT_AUTHOR_EMAIL.to_string(),
@@ -144,11 +470,22 @@ impl Default for ReleasePrConfig {
             changelog: ChangelogConfig {
                 enabled: true,
                 output_file: DEFAULT_CHANGELOG_OUTPUT_FILE.to_string(),
+                since_previous_tag: false,
+                config_file: None,
+                include_types: Vec::new(),
+                tag_template: None,
             },
+            github: GithubConfig { use_api: false },
             tagging: TaggingConfig {
                 enabled: DEFAULT_TAGGING_ENABLED,
                 tag_template: tag_template::DEFAULT_TAG_TEMPLATE.to_string(),
+                annotated: false,
+                sign: false,
+                message_template: tag_template::DEFAULT_TAG_MESSAGE_TEMPLATE.to_string(),
+                require_annotated_for_baseline: false,
             },
+            groups: BTreeMap::new(),
+            scopes: Vec::new(),
         }
     }
 }
@@ -156,6 +493,9 @@ impl Default for ReleasePrConfig {
 #[derive(Debug, Clone)]
 pub struct ResolvedConfig {
     pub provider: Provider,
+    /// Every provider `brel init` writes a workflow for; `provider` is the first of them.
+    pub providers: Vec<Provider>,
+    pub profile: Option<String>,
     pub default_branch: String,
     pub workflow_file: String,
     pub release_pr: ReleasePrConfig,
@@ -163,23 +503,97 @@ pub struct ResolvedConfig {
     pub warnings: Vec<String>,
 }
 
+impl ResolvedConfig {
+    /// Narrows the config to one `release_pr.groups` entry so the release flow can run per group.
+    pub fn for_group(&self, name: &str) -> Option<ResolvedConfig> {
+        let group = self.release_pr.groups.get(name)?;
+        let mut config = self.clone();
+        let release_pr = &mut config.release_pr;
+        release_pr.version_updates = group.version_updates.clone();
+        release_pr.selector_formats = group.selector_formats.clone();
+        release_pr.tagging.tag_template = group.tag_template.clone();
+        release_pr.release_branch_pattern = group.release_branch_pattern.clone();
+        release_pr.prerelease_branch_pattern = group.prerelease_branch_pattern.clone();
+        release_pr.scopes = group.scopes.clone();
+        release_pr.managed_marker = format!("{} group:{name}", release_pr.managed_marker);
+        release_pr.groups.clear();
+        Some(config)
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct RawConfig {
     provider: Option<String>,
+    providers: Option<Vec<String>>,
     default_branch: Option<String>,
     workflow_file: Option<String>,
+    strict_keys: Option<bool>,
     release_pr: Option<RawReleasePrConfig>,
 }
 
 #[derive(Debug, Default, Deserialize)]
 struct RawReleasePrConfig {
-    version_updates: Option<BTreeMap<String, Vec<String>>>,
+    version_updates: Option<BTreeMap<String, Vec<RawVersionSelector>>>,
     format_overrides: Option<BTreeMap<String, String>>,
+    tag_files: Option<Vec<String>>,
+    allow_empty_globs: Option<bool>,
+    allow_missing_selectors: Option<bool>,
+    strict_single_match: Option<bool>,
+    exclude: Option<Vec<String>>,
     release_branch_pattern: Option<String>,
+    prerelease_branch_pattern: Option<String>,
+    release_branch_slugify: Option<bool>,
+    cleanup_stale_branches: Option<bool>,
     pr_template_file: Option<String>,
+    pr_title_template: Option<String>,
+    milestone: Option<String>,
+    notes_output_file: Option<String>,
+    post_update_command: Option<String>,
+    commit_body_template: Option<String>,
+    commit_trailers: Option<Vec<String>>,
+    commit_signoff: Option<bool>,
+    run_hooks: Option<bool>,
+    all_tags: Option<bool>,
+    include_prereleases: Option<bool>,
+    lock: Option<bool>,
+    lock_timeout_secs: Option<u64>,
+    release_as_footer: Option<String>,
+    build_metadata_template: Option<String>,
+    minimum_bump: Option<String>,
+    release_threshold: Option<String>,
+    trailer_bump: Option<bool>,
+    breaking_change_as: Option<String>,
+    ignore_merges: Option<bool>,
+    commit_format: Option<String>,
+    non_utf8_commits: Option<String>,
+    mode: Option<String>,
+    managed_marker: Option<String>,
+    version_source: Option<String>,
+    issue_pattern: Option<String>,
+    max_body_commits: Option<usize>,
+    type_aliases: Option<BTreeMap<String, String>>,
     commit_author: Option<RawCommitAuthorConfig>,
     changelog: Option<RawChangelogConfig>,
+    github: Option<RawGithubConfig>,
     tagging: Option<RawTaggingConfig>,
+    groups: Option<BTreeMap<String, RawReleaseGroupConfig>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RawReleaseGroupConfig {
+    scopes: Option<Vec<String>>,
+    version_updates: Option<BTreeMap<String, Vec<RawVersionSelector>>>,
+    tag_template: Option<String>,
+    release_branch_pattern: Option<String>,
+    prerelease_branch_pattern: Option<String>,
+}
+
+/// A `version_updates` entry: a bare selector, or `{ selector, format }` to pin its format.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RawVersionSelector {
+    Plain(String),
+    WithFormat { selector: String, format: String },
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -192,15 +606,47 @@ struct RawCommitAuthorConfig {
 struct RawChangelogConfig {
     enabled: Option<bool>,
     output_file: Option<String>,
+    since_previous_tag: Option<bool>,
+    config_file: Option<String>,
+    include_types: Option<Vec<String>>,
+    tag_template: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RawGithubConfig {
+    use_api: Option<bool>,
 }
 
 #[derive(Debug, Default, Deserialize)]
 struct RawTaggingConfig {
     enabled: Option<bool>,
     tag_template: Option<String>,
+    annotated: Option<bool>,
+    sign: Option<bool>,
+    message_template: Option<String>,
+    require_annotated_for_baseline: Option<bool>,
+}
+
+pub fn load(explicit_path: Option<&Path>, cwd: &Path) -> Result<ResolvedConfig, BrelError> {
+    load_with_profile(explicit_path, cwd, None)
+}
+
+/// Loads the config like [`load`], then deep-merges `[profile.<name>]` over the base settings.
+pub fn load_with_profile(
+    explicit_path: Option<&Path>,
+    cwd: &Path,
+    profile: Option<&str>,
+) -> Result<ResolvedConfig, BrelError> {
+    load_config_file(explicit_path, cwd, profile)
+        .map_err(|err| BrelError::classify(err, BrelError::Config))
 }
 
-pub fn load(explicit_path: Option<&Path>, cwd: &Path) -> Result<ResolvedConfig> {
+fn load_config_file(
+    explicit_path: Option<&Path>,
+    cwd: &Path,
+    profile: Option<&str>,
+) -> Result<ResolvedConfig> {
+    let profile = profile.map(str::trim);
     let config_location = resolve_config_location(explicit_path, cwd)?;
 
     let (source, raw_contents) = match config_location {
@@ -215,8 +661,13 @@ pub fn load(explicit_path: Option<&Path>, cwd: &Path) -> Result<ResolvedConfig>
                 .with_context(|| format!("Failed to read config file `{}`.", path.display()))?,
         ),
         None => {
+            if let Some(profile) = profile {
+                bail!("Unknown profile `{profile}`: no config file was found.");
+            }
             return Ok(ResolvedConfig {
                 provider: Provider::Github,
+                providers: vec![Provider::Github],
+                profile: None,
                 default_branch: DEFAULT_BRANCH.to_string(),
                 workflow_file: DEFAULT_WORKFLOW_FILE.to_string(),
                 release_pr: ReleasePrConfig::default(),
@@ -226,13 +677,14 @@ pub fn load(explicit_path: Option<&Path>, cwd: &Path) -> Result<ResolvedConfig>
         }
     };
 
-    let parsed_toml = raw_contents.parse::<toml::Value>().with_context(|| {
+    let mut parsed_toml = raw_contents.parse::<toml::Value>().with_context(|| {
         let path = source.path().expect("config source always has path");
         format!("Config file `{}` is not valid TOML.", path.display())
     })?;
-    let warnings = collect_warnings(&parsed_toml);
+    apply_profile(&mut parsed_toml, profile)?;
+    let unknown_keys = collect_unknown_keys(&parsed_toml);
 
-    let raw: RawConfig = toml::from_str(&raw_contents).with_context(|| {
+    let raw: RawConfig = parsed_toml.try_into().with_context(|| {
         let path = source.path().expect("config source always has path");
         format!(
             "Config file `{}` has unsupported value types.",
@@ -240,10 +692,47 @@ pub fn load(explicit_path: Option<&Path>, cwd: &Path) -> Result<ResolvedConfig>
         )
     })?;
 
-    let provider = match raw.provider {
-        Some(value) => Provider::from_str(&value)?,
-        None => Provider::Github,
+    let mut warnings = Vec::new();
+    match raw.strict_keys {
+        Some(true) if !unknown_keys.is_empty() => {
+            let path = source.path().expect("config source always has path");
+            bail!(
+                "Config file `{}` has unknown keys and `strict_keys = true`: {}.",
+                path.display(),
+                unknown_keys
+                    .iter()
+                    .map(|key| format!("`{key}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+        Some(false) => {}
+        _ => warnings.extend(
+            unknown_keys
+                .iter()
+                .map(|key| format!("Unknown config key `{key}` was ignored.")),
+        ),
+    }
+
+    let providers = match (raw.provider, raw.providers) {
+        (Some(_), Some(_)) => bail!("Set either `provider` or `providers`, not both."),
+        (Some(value), None) => vec![Provider::from_str(&value)?],
+        (None, Some(values)) => {
+            let mut providers = Vec::new();
+            for value in values {
+                let provider = Provider::from_str(&value)?;
+                if !providers.contains(&provider) {
+                    providers.push(provider);
+                }
+            }
+            if providers.is_empty() {
+                bail!("`providers` cannot be empty.");
+            }
+            providers
+        }
+        (None, None) => vec![Provider::Github],
     };
+    let provider = providers[0];
 
     let default_branch = raw
         .default_branch
@@ -264,9 +753,18 @@ pub fn load(explicit_path: Option<&Path>, cwd: &Path) -> Result<ResolvedConfig>
     }
 
     let release_pr = resolve_release_pr_config(raw.release_pr)?;
+    if release_pr.tagging.enabled && release_pr.pr_title_template.is_some() {
+        warnings.push(
+            "`release_pr.pr_title_template` is set while tagging is enabled; tag-on-merge only \
+             recognizes PR titles of the form `Release <tag>`."
+                .to_string(),
+        );
+    }
 
     Ok(ResolvedConfig {
         provider,
+        providers,
+        profile: profile.map(str::to_string),
         default_branch,
         workflow_file,
         release_pr,
@@ -280,32 +778,21 @@ fn resolve_release_pr_config(raw: Option<RawReleasePrConfig>) -> Result<ReleaseP
         return Ok(ReleasePrConfig::default());
     };
 
-    let mut version_updates = BTreeMap::new();
-    for (path, keys) in raw_release_pr.version_updates.unwrap_or_default() {
-        let normalized_path =
-            normalize_repo_relative_path(&path, "`release_pr.version_updates` path")?;
-        if keys.is_empty() {
-            bail!("`release_pr.version_updates[\"{normalized_path}\"]` cannot be empty.");
-        }
-
-        let mut normalized_keys = Vec::with_capacity(keys.len());
-        for key in keys {
-            normalized_keys.push(normalize_version_selector(&key)?);
-        }
-
-        if version_updates
-            .insert(normalized_path.clone(), normalized_keys)
-            .is_some()
-        {
-            bail!("Duplicate `release_pr.version_updates` path `{normalized_path}`.");
-        }
-    }
+    let (version_updates, selector_formats) = normalize_version_updates(
+        raw_release_pr.version_updates.unwrap_or_default(),
+        "release_pr.version_updates",
+    )?;
 
     let mut format_overrides = BTreeMap::new();
     for (path, format_value) in raw_release_pr.format_overrides.unwrap_or_default() {
         let normalized_path =
             normalize_repo_relative_path(&path, "`release_pr.format_overrides` path")?;
-        if !version_updates.contains_key(&normalized_path) {
+        let covered_by_version_updates = version_updates.keys().any(|update_path| {
+            update_path == &normalized_path
+                || (path_glob::is_glob_pattern(update_path)
+                    && path_glob::matches(update_path, &normalized_path))
+        });
+        if !path_glob::is_glob_pattern(&normalized_path) && !covered_by_version_updates {
             bail!(
                 "`release_pr.format_overrides` includes `{normalized_path}`, but no matching \
                  `release_pr.version_updates` entry exists."
@@ -321,6 +808,13 @@ fn resolve_release_pr_config(raw: Option<RawReleasePrConfig>) -> Result<ReleaseP
         }
     }
 
+    let tag_files = raw_release_pr
+        .tag_files
+        .unwrap_or_default()
+        .iter()
+        .map(|path| normalize_repo_relative_path(path, "`release_pr.tag_files` path"))
+        .collect::<Result<Vec<_>>>()?;
+
     let release_branch_pattern = raw_release_pr
         .release_branch_pattern
         .unwrap_or_else(|| DEFAULT_RELEASE_BRANCH_PATTERN.to_string())
@@ -330,6 +824,17 @@ fn resolve_release_pr_config(raw: Option<RawReleasePrConfig>) -> Result<ReleaseP
         bail!("`release_pr.release_branch_pattern` cannot be empty.");
     }
     validate_branch_pattern(&release_branch_pattern)?;
+    let prerelease_branch_pattern = raw_release_pr
+        .prerelease_branch_pattern
+        .unwrap_or_else(|| DEFAULT_PRERELEASE_BRANCH_PATTERN.to_string())
+        .trim()
+        .to_string();
+    if prerelease_branch_pattern.is_empty() {
+        bail!("`release_pr.prerelease_branch_pattern` cannot be empty.");
+    }
+    validate_branch_pattern(&prerelease_branch_pattern)?;
+    let release_branch_slugify = raw_release_pr.release_branch_slugify.unwrap_or(false);
+    let cleanup_stale_branches = raw_release_pr.cleanup_stale_branches.unwrap_or(false);
 
     let pr_template_file = match raw_release_pr.pr_template_file {
         Some(path) => {
@@ -340,6 +845,186 @@ fn resolve_release_pr_config(raw: Option<RawReleasePrConfig>) -> Result<ReleaseP
         None => None,
     };
 
+    let pr_title_template = match raw_release_pr.pr_title_template {
+        Some(template) => {
+            let trimmed = template.trim().to_string();
+            if trimmed.is_empty() {
+                bail!("`release_pr.pr_title_template` cannot be empty.");
+            }
+            Some(trimmed)
+        }
+        None => None,
+    };
+
+    let milestone = match raw_release_pr.milestone {
+        Some(milestone) => {
+            let trimmed = milestone.trim().to_string();
+            if trimmed.is_empty() {
+                bail!("`release_pr.milestone` cannot be empty.");
+            }
+            let leftover = trimmed.replace("{version}", "").replace("{tag}", "");
+            if leftover.contains('{') || leftover.contains('}') {
+                bail!("`release_pr.milestone` supports only `{{version}}` and `{{tag}}` tokens.");
+            }
+            Some(trimmed)
+        }
+        None => None,
+    };
+
+    let post_update_command = match raw_release_pr.post_update_command {
+        Some(command) => {
+            let trimmed = command.trim().to_string();
+            if trimmed.is_empty() {
+                bail!("`release_pr.post_update_command` cannot be empty.");
+            }
+            Some(trimmed)
+        }
+        None => None,
+    };
+
+    let commit_body_template = match raw_release_pr.commit_body_template {
+        Some(template) => {
+            let trimmed = template.trim().to_string();
+            if trimmed.is_empty() {
+                bail!("`release_pr.commit_body_template` cannot be empty.");
+            }
+            let leftover = trimmed.replace("{version}", "").replace("{tag}", "");
+            if leftover.contains('{') || leftover.contains('}') {
+                bail!(
+                    "`release_pr.commit_body_template` supports only `{{version}}` and `{{tag}}` \
+                     tokens."
+                );
+            }
+            Some(trimmed)
+        }
+        None => None,
+    };
+
+    let mut commit_trailers = Vec::new();
+    for trailer in raw_release_pr.commit_trailers.unwrap_or_default() {
+        let trailer = trailer.trim().to_string();
+        // git only recognizes `Token: value` lines whose token has no spaces.
+        let valid = trailer.split_once(": ").is_some_and(|(key, value)| {
+            !key.is_empty() && !key.contains(char::is_whitespace) && !value.trim().is_empty()
+        });
+        if !valid || trailer.contains('\n') {
+            bail!("`release_pr.commit_trailers` entry `{trailer}` must look like `Key: value`.");
+        }
+        commit_trailers.push(trailer);
+    }
+    let commit_signoff = raw_release_pr.commit_signoff.unwrap_or(false);
+
+    let notes_output_file = match raw_release_pr.notes_output_file {
+        Some(path) => Some(normalize_repo_relative_path(
+            &path,
+            "`release_pr.notes_output_file` path",
+        )?),
+        None => None,
+    };
+
+    let allow_empty_globs = raw_release_pr.allow_empty_globs.unwrap_or(false);
+    let allow_missing_selectors = raw_release_pr.allow_missing_selectors.unwrap_or(false);
+    let strict_single_match = raw_release_pr.strict_single_match.unwrap_or(false);
+    let exclude = raw_release_pr
+        .exclude
+        .unwrap_or_default()
+        .into_iter()
+        .map(|pattern| {
+            let trimmed = pattern.trim().to_string();
+            if trimmed.is_empty() {
+                bail!("`release_pr.exclude` entries cannot be empty.");
+            }
+            Ok(trimmed)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let run_hooks = raw_release_pr.run_hooks.unwrap_or(DEFAULT_RUN_HOOKS);
+    let all_tags = raw_release_pr.all_tags.unwrap_or(false);
+    let include_prereleases = raw_release_pr.include_prereleases.unwrap_or(false);
+    let lock = raw_release_pr.lock.unwrap_or(false);
+    let lock_timeout_secs = raw_release_pr
+        .lock_timeout_secs
+        .unwrap_or(DEFAULT_LOCK_TIMEOUT_SECS);
+    if lock_timeout_secs == 0 {
+        bail!("`release_pr.lock_timeout_secs` must be greater than zero.");
+    }
+    let minimum_bump = match raw_release_pr.minimum_bump {
+        Some(value) => {
+            Some(BumpLevel::from_str(&value).context("Invalid `release_pr.minimum_bump`.")?)
+        }
+        None => None,
+    };
+    let release_threshold = match raw_release_pr.release_threshold {
+        Some(value) => {
+            Some(BumpLevel::from_str(&value).context("Invalid `release_pr.release_threshold`.")?)
+        }
+        None => None,
+    };
+    let trailer_bump = raw_release_pr.trailer_bump.unwrap_or(false);
+    let breaking_change_as = match raw_release_pr.breaking_change_as {
+        Some(value) => {
+            BumpLevel::from_str(&value).context("Invalid `release_pr.breaking_change_as`.")?
+        }
+        None => BumpLevel::Major,
+    };
+    let ignore_merges = raw_release_pr.ignore_merges.unwrap_or(true);
+    let commit_format = match raw_release_pr.commit_format {
+        Some(value) => {
+            CommitFormat::from_str(&value).context("Invalid `release_pr.commit_format`.")?
+        }
+        None => CommitFormat::Conventional,
+    };
+    let non_utf8_commits = match raw_release_pr.non_utf8_commits {
+        Some(value) => NonUtf8CommitPolicy::from_str(&value)
+            .context("Invalid `release_pr.non_utf8_commits`.")?,
+        None => NonUtf8CommitPolicy::Keep,
+    };
+    let mode = match raw_release_pr.mode {
+        Some(value) => ReleaseMode::from_str(&value).context("Invalid `release_pr.mode`.")?,
+        None => ReleaseMode::Pr,
+    };
+    let managed_marker = normalize_managed_marker(raw_release_pr.managed_marker.as_deref())?;
+    let version_source = match raw_release_pr.version_source {
+        Some(value) => {
+            VersionSource::from_str(&value).context("Invalid `release_pr.version_source`.")?
+        }
+        None => VersionSource::Tags,
+    };
+    let issue_pattern = match raw_release_pr.issue_pattern {
+        Some(pattern) => {
+            let trimmed = pattern.trim().to_string();
+            if trimmed.is_empty() {
+                bail!("`release_pr.issue_pattern` cannot be empty.");
+            }
+            regex::Regex::new(&trimmed).context("Invalid `release_pr.issue_pattern`.")?;
+            trimmed
+        }
+        None => DEFAULT_ISSUE_PATTERN.to_string(),
+    };
+    let max_body_commits = raw_release_pr.max_body_commits;
+    if max_body_commits == Some(0) {
+        bail!("`release_pr.max_body_commits` must be greater than zero.");
+    }
+    let type_aliases = normalize_type_aliases(raw_release_pr.type_aliases.unwrap_or_default())?;
+    let release_as_footer = match raw_release_pr.release_as_footer {
+        Some(footer) => {
+            let trimmed = footer.trim().to_string();
+            if trimmed.is_empty() || trimmed.contains(':') || trimmed.contains(char::is_whitespace)
+            {
+                bail!("`release_pr.release_as_footer` must be a footer key such as `Release-As`.");
+            }
+            Some(trimmed)
+        }
+        None => None,
+    };
+
+    let build_metadata_template = match raw_release_pr.build_metadata_template {
+        Some(template) => Some(
+            tag_template::normalize_build_metadata_template(&template)
+                .context("Invalid `release_pr.build_metadata_template`.")?,
+        ),
+        None => None,
+    };
+
     let raw_author = raw_release_pr.commit_author.unwrap_or_default();
     let commit_author_name = raw_author
         .name
@@ -359,6 +1044,16 @@ fn resolve_release_pr_config(raw: Option<RawReleasePrConfig>) -> Result<ReleaseP
         bail!("`release_pr.commit_author.email` cannot be empty.");
     }
 
+    let github_use_api = raw_release_pr
+        .github
+        .unwrap_or_default()
+        .use_api
+        .unwrap_or(false);
+    // The REST API addresses milestones by number, so only the `gh` path can set one by title.
+    if github_use_api && milestone.is_some() {
+        bail!("`release_pr.milestone` is not supported with `release_pr.github.use_api = true`.");
+    }
+
     let raw_changelog = raw_release_pr.changelog.unwrap_or_default();
     let changelog_enabled = raw_changelog.enabled.unwrap_or(true);
     let changelog_output_file = normalize_repo_relative_path(
@@ -368,6 +1063,28 @@ fn resolve_release_pr_config(raw: Option<RawReleasePrConfig>) -> Result<ReleaseP
             .unwrap_or(DEFAULT_CHANGELOG_OUTPUT_FILE),
         "`release_pr.changelog.output_file` path",
     )?;
+    let changelog_since_previous_tag = raw_changelog.since_previous_tag.unwrap_or(false);
+    let changelog_config_file = raw_changelog
+        .config_file
+        .as_deref()
+        .map(|path| normalize_repo_relative_path(path, "`release_pr.changelog.config_file` path"))
+        .transpose()?;
+    let mut changelog_include_types = Vec::new();
+    for commit_type in raw_changelog.include_types.unwrap_or_default() {
+        let commit_type = commit_type.trim().to_ascii_lowercase();
+        if commit_type.is_empty() {
+            bail!("`release_pr.changelog.include_types` entries cannot be empty.");
+        }
+        if !changelog_include_types.contains(&commit_type) {
+            changelog_include_types.push(commit_type);
+        }
+    }
+    let changelog_tag_template = raw_changelog
+        .tag_template
+        .as_deref()
+        .map(tag_template::normalize_tag_template)
+        .transpose()
+        .context("Invalid `release_pr.changelog.tag_template`.")?;
     let raw_tagging = raw_release_pr.tagging.unwrap_or_default();
     let tagging_enabled = raw_tagging.enabled.unwrap_or(DEFAULT_TAGGING_ENABLED);
     let tag_template = tag_template::normalize_tag_template(
@@ -377,12 +1094,98 @@ fn resolve_release_pr_config(raw: Option<RawReleasePrConfig>) -> Result<ReleaseP
             .unwrap_or(tag_template::DEFAULT_TAG_TEMPLATE),
     )
     .context("Invalid `release_pr.tagging.tag_template`.")?;
+    let tagging_annotated = raw_tagging.annotated.unwrap_or(false);
+    let tagging_sign = raw_tagging.sign.unwrap_or(false);
+    let message_template = tag_template::normalize_tag_message_template(
+        raw_tagging
+            .message_template
+            .as_deref()
+            .unwrap_or(tag_template::DEFAULT_TAG_MESSAGE_TEMPLATE),
+    )
+    .context("Invalid `release_pr.tagging.message_template`.")?;
+    let require_annotated_for_baseline =
+        raw_tagging.require_annotated_for_baseline.unwrap_or(false);
+
+    let mut groups = BTreeMap::new();
+    for (name, raw_group) in raw_release_pr.groups.unwrap_or_default() {
+        let (name, group) = resolve_release_group(&name, raw_group)?;
+        if groups.insert(name.clone(), group).is_some() {
+            bail!("Duplicate `release_pr.groups` name `{name}`.");
+        }
+    }
+    // Groups replace the top-level `version_updates`, so their files are the ones tag files
+    // have to name.
+    for tag_file in tag_files
+        .iter()
+        .filter(|path| !path_glob::is_glob_pattern(path))
+    {
+        if groups.is_empty() {
+            if !version_updates.contains_key(tag_file) {
+                bail!(
+                    "`release_pr.tag_files` includes `{tag_file}`, but no matching \
+                     `release_pr.version_updates` entry exists."
+                );
+            }
+        } else if !groups
+            .values()
+            .any(|group| group.version_updates.contains_key(tag_file))
+        {
+            bail!(
+                "`release_pr.tag_files` includes `{tag_file}`, but no \
+                 `release_pr.groups.<name>.version_updates` entry lists it."
+            );
+        }
+    }
+    // The generated tag job only recognizes the top-level marker and tag template.
+    if !groups.is_empty() && tagging_enabled {
+        bail!(
+            "`release_pr.groups` cannot be combined with `release_pr.tagging.enabled = true` \
+             yet: the tag job does not recognize group release PRs, so merged groups would never \
+             be tagged."
+        );
+    }
 
     Ok(ReleasePrConfig {
         version_updates,
+        selector_formats,
         format_overrides,
+        tag_files,
+        allow_empty_globs,
+        allow_missing_selectors,
+        strict_single_match,
+        exclude,
         release_branch_pattern,
+        prerelease_branch_pattern,
+        release_branch_slugify,
+        cleanup_stale_branches,
         pr_template_file,
+        pr_title_template,
+        milestone,
+        notes_output_file,
+        post_update_command,
+        commit_body_template,
+        commit_trailers,
+        commit_signoff,
+        run_hooks,
+        all_tags,
+        include_prereleases,
+        lock,
+        lock_timeout_secs,
+        release_as_footer,
+        build_metadata_template,
+        minimum_bump,
+        release_threshold,
+        trailer_bump,
+        breaking_change_as,
+        ignore_merges,
+        commit_format,
+        non_utf8_commits,
+        mode,
+        managed_marker,
+        version_source,
+        issue_pattern,
+        max_body_commits,
+        type_aliases,
         commit_author: CommitAuthorConfig {
             name: commit_author_name,
             email: commit_author_email,
@@ -390,18 +1193,157 @@ fn resolve_release_pr_config(raw: Option<RawReleasePrConfig>) -> Result<ReleaseP
         changelog: ChangelogConfig {
             enabled: changelog_enabled,
             output_file: changelog_output_file,
+            since_previous_tag: changelog_since_previous_tag,
+            config_file: changelog_config_file,
+            include_types: changelog_include_types,
+            tag_template: changelog_tag_template,
+        },
+        github: GithubConfig {
+            use_api: github_use_api,
         },
         tagging: TaggingConfig {
             enabled: tagging_enabled,
             tag_template,
+            annotated: tagging_annotated,
+            sign: tagging_sign,
+            message_template,
+            require_annotated_for_baseline,
         },
+        groups,
+        scopes: Vec::new(),
     })
 }
 
-fn normalize_repo_relative_path(value: &str, label: &str) -> Result<String> {
-    let trimmed = value.trim();
-    if trimmed.is_empty() {
-        bail!("{label} cannot be empty.");
+fn normalize_version_updates(
+    raw: BTreeMap<String, Vec<RawVersionSelector>>,
+    label: &str,
+) -> Result<(BTreeMap<String, Vec<String>>, SelectorFormats)> {
+    let mut version_updates = BTreeMap::new();
+    let mut selector_formats = SelectorFormats::new();
+    for (path, keys) in raw {
+        let normalized_path = normalize_repo_relative_path(&path, &format!("`{label}` path"))?;
+        if keys.is_empty() {
+            bail!("`{label}[\"{normalized_path}\"]` cannot be empty.");
+        }
+
+        let mut normalized_keys = Vec::with_capacity(keys.len());
+        for key in keys {
+            let (selector, format) = match key {
+                RawVersionSelector::Plain(selector) => (selector, None),
+                RawVersionSelector::WithFormat { selector, format } => (selector, Some(format)),
+            };
+            let selector = normalize_version_selector(&selector)?;
+            if let Some(format) = format {
+                let format = VersionFileFormat::from_str(&format).with_context(|| {
+                    format!(
+                        "Invalid format for selector `{selector}` in \
+                         `{label}[\"{normalized_path}\"]`."
+                    )
+                })?;
+                let previous = selector_formats
+                    .entry(normalized_path.clone())
+                    .or_default()
+                    .insert(selector.clone(), format);
+                if previous.is_some_and(|previous| previous != format) {
+                    bail!(
+                        "Selector `{selector}` in `{label}[\"{normalized_path}\"]` is given \
+                         more than one format."
+                    );
+                }
+            }
+            normalized_keys.push(selector);
+        }
+
+        if version_updates
+            .insert(normalized_path.clone(), normalized_keys)
+            .is_some()
+        {
+            bail!("Duplicate `{label}` path `{normalized_path}`.");
+        }
+    }
+    Ok((version_updates, selector_formats))
+}
+
+fn resolve_release_group(
+    raw_name: &str,
+    raw: RawReleaseGroupConfig,
+) -> Result<(String, ReleaseGroupConfig)> {
+    let name = raw_name.trim().to_string();
+    // The name ends up in branch names, tags, and the managed PR marker.
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_'))
+    {
+        bail!(
+            "`release_pr.groups` name `{raw_name}` may only contain ASCII letters, digits, `-`, \
+             and `_`."
+        );
+    }
+    let label = format!("release_pr.groups.{name}");
+
+    let scopes = raw
+        .scopes
+        .unwrap_or_default()
+        .into_iter()
+        .map(|scope| scope.trim().to_ascii_lowercase())
+        .filter(|scope| !scope.is_empty())
+        .collect::<Vec<_>>();
+    if scopes.is_empty() {
+        bail!("`{label}.scopes` must list at least one commit scope.");
+    }
+
+    let (version_updates, selector_formats) = normalize_version_updates(
+        raw.version_updates.unwrap_or_default(),
+        &format!("{label}.version_updates"),
+    )?;
+    if version_updates.is_empty() {
+        bail!("`{label}.version_updates` must list at least one file.");
+    }
+
+    let tag_template = tag_template::normalize_tag_template(
+        raw.tag_template
+            .as_deref()
+            .unwrap_or(&format!("{name}-{}", tag_template::DEFAULT_TAG_TEMPLATE)),
+    )
+    .with_context(|| format!("Invalid `{label}.tag_template`."))?;
+
+    let release_branch_pattern = raw
+        .release_branch_pattern
+        .unwrap_or_else(|| format!("brel/release/{name}/v{{{{version}}}}"))
+        .trim()
+        .to_string();
+    if release_branch_pattern.is_empty() {
+        bail!("`{label}.release_branch_pattern` cannot be empty.");
+    }
+    validate_branch_pattern(&release_branch_pattern)?;
+    let prerelease_branch_pattern = raw
+        .prerelease_branch_pattern
+        .unwrap_or_else(|| format!("brel/prerelease/{name}/v{{{{version}}}}"))
+        .trim()
+        .to_string();
+    if prerelease_branch_pattern.is_empty() {
+        bail!("`{label}.prerelease_branch_pattern` cannot be empty.");
+    }
+    validate_branch_pattern(&prerelease_branch_pattern)?;
+
+    Ok((
+        name,
+        ReleaseGroupConfig {
+            scopes,
+            version_updates,
+            selector_formats,
+            tag_template,
+            release_branch_pattern,
+            prerelease_branch_pattern,
+        },
+    ))
+}
+
+fn normalize_repo_relative_path(value: &str, label: &str) -> Result<String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        bail!("{label} cannot be empty.");
     }
 
     let path = Path::new(trimmed);
@@ -425,6 +1367,65 @@ fn normalize_repo_relative_path(value: &str, label: &str) -> Result<String> {
     Ok(trimmed.to_string())
 }
 
+fn normalize_managed_marker(raw: Option<&str>) -> Result<String> {
+    let Some(raw) = raw else {
+        return Ok(DEFAULT_MANAGED_MARKER.to_string());
+    };
+    let marker = raw.trim();
+    if marker.is_empty() {
+        bail!("`release_pr.managed_marker` cannot be empty.");
+    }
+    // The marker ends up inside YAML comments, HTML comments, and a `grep -E` pattern in the
+    // generated workflow, so keep it to characters that need no escaping in any of them.
+    if !marker
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, ' ' | '-' | '_' | ':' | '/' | '@'))
+    {
+        bail!(
+            "`release_pr.managed_marker` may only contain ASCII letters, digits, spaces, and \
+             `-_:/@`."
+        );
+    }
+    Ok(marker.to_string())
+}
+
+fn normalize_type_aliases(raw: BTreeMap<String, String>) -> Result<BTreeMap<String, String>> {
+    let mut aliases = BTreeMap::new();
+    for (alias, target) in raw {
+        let alias = alias.trim().to_ascii_lowercase();
+        let target = target.trim().to_ascii_lowercase();
+        if alias.is_empty() || target.is_empty() {
+            bail!("`release_pr.type_aliases` entries cannot be empty.");
+        }
+        if aliases.insert(alias.clone(), target).is_some() {
+            bail!("Duplicate `release_pr.type_aliases` entry `{alias}`.");
+        }
+    }
+
+    // Flatten chains (`bug -> bugfix -> fix`) so commits need a single lookup.
+    let mut flattened = BTreeMap::new();
+    for alias in aliases.keys() {
+        let mut seen = vec![alias.as_str()];
+        let mut target = aliases[alias].as_str();
+        while let Some(next) = aliases.get(target) {
+            if seen.contains(&target) {
+                seen.push(target);
+                bail!(
+                    "`release_pr.type_aliases` contains a cycle: {}.",
+                    seen.iter()
+                        .map(|kind| format!("`{kind}`"))
+                        .collect::<Vec<_>>()
+                        .join(" -> ")
+                );
+            }
+            seen.push(target);
+            target = next;
+        }
+        flattened.insert(alias.clone(), target.to_string());
+    }
+    Ok(flattened)
+}
+
 fn normalize_version_selector(value: &str) -> Result<String> {
     let trimmed = value.trim();
     if trimmed.is_empty() {
@@ -462,48 +1463,137 @@ fn validate_branch_pattern(pattern: &str) -> Result<()> {
     Ok(())
 }
 
-fn collect_warnings(parsed: &toml::Value) -> Vec<String> {
-    let mut warnings = Vec::new();
+fn apply_profile(parsed: &mut toml::Value, profile: Option<&str>) -> Result<()> {
+    let Some(root) = parsed.as_table_mut() else {
+        return Ok(());
+    };
+    let profiles = match root.remove("profile") {
+        Some(toml::Value::Table(profiles)) => profiles,
+        Some(_) => bail!("`profile` must be a table of named profiles."),
+        None => toml::value::Table::new(),
+    };
+    let Some(name) = profile else {
+        return Ok(());
+    };
+
+    let Some(overrides) = profiles.get(name) else {
+        if profiles.is_empty() {
+            bail!(
+                "Unknown profile `{name}`: the config file defines no `[profile.<name>]` tables."
+            );
+        }
+        bail!(
+            "Unknown profile `{name}`. Available profiles: {}.",
+            profiles
+                .keys()
+                .map(|key| format!("`{key}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    };
+    let Some(overrides) = overrides.as_table() else {
+        bail!("`profile.{name}` must be a table.");
+    };
+    merge_toml_tables(root, overrides.clone());
+    Ok(())
+}
+
+fn merge_toml_tables(base: &mut toml::value::Table, overrides: toml::value::Table) {
+    for (key, value) in overrides {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base_table)), toml::Value::Table(override_table)) => {
+                merge_toml_tables(base_table, override_table);
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+fn collect_unknown_keys(parsed: &toml::Value) -> Vec<String> {
+    let mut unknown_keys = Vec::new();
     let Some(root) = parsed.as_table() else {
-        return warnings;
+        return unknown_keys;
     };
 
-    let allowed_root: BTreeSet<&str> =
-        BTreeSet::from(["provider", "default_branch", "workflow_file", "release_pr"]);
+    let allowed_root: BTreeSet<&str> = BTreeSet::from([
+        "provider",
+        "providers",
+        "default_branch",
+        "workflow_file",
+        "strict_keys",
+        "release_pr",
+    ]);
     for key in root
         .keys()
         .filter(|key| !allowed_root.contains(key.as_str()))
     {
-        warnings.push(format!("Unknown config key `{key}` was ignored."));
+        unknown_keys.push(key.clone());
     }
 
     let Some(release_pr) = root.get("release_pr").and_then(toml::Value::as_table) else {
-        return warnings;
+        return unknown_keys;
     };
 
     let allowed_release_pr: BTreeSet<&str> = BTreeSet::from([
         "version_updates",
         "format_overrides",
+        "tag_files",
+        "allow_empty_globs",
+        "allow_missing_selectors",
+        "strict_single_match",
+        "exclude",
         "release_branch_pattern",
+        "prerelease_branch_pattern",
+        "release_branch_slugify",
+        "cleanup_stale_branches",
         "pr_template_file",
+        "pr_title_template",
+        "milestone",
+        "notes_output_file",
+        "post_update_command",
+        "commit_body_template",
+        "commit_trailers",
+        "commit_signoff",
+        "run_hooks",
+        "all_tags",
+        "include_prereleases",
+        "lock",
+        "lock_timeout_secs",
+        "release_as_footer",
+        "build_metadata_template",
+        "minimum_bump",
+        "release_threshold",
+        "trailer_bump",
+        "breaking_change_as",
+        "ignore_merges",
+        "commit_format",
+        "non_utf8_commits",
+        "mode",
+        "managed_marker",
+        "version_source",
+        "issue_pattern",
+        "max_body_commits",
+        "type_aliases",
         "commit_author",
         "changelog",
+        "github",
         "tagging",
+        "groups",
     ]);
     for key in release_pr
         .keys()
         .filter(|key| !allowed_release_pr.contains(key.as_str()))
     {
-        warnings.push(format!(
-            "Unknown config key `release_pr.{key}` was ignored."
-        ));
+        unknown_keys.push(format!("release_pr.{key}"));
     }
 
     let Some(commit_author) = release_pr
         .get("commit_author")
         .and_then(toml::Value::as_table)
     else {
-        return collect_release_pr_nested_warnings(release_pr, warnings);
+        return collect_release_pr_nested_unknown_keys(release_pr, unknown_keys);
     };
 
     let allowed_author: BTreeSet<&str> = BTreeSet::from(["name", "email"]);
@@ -511,43 +1601,78 @@ fn collect_warnings(parsed: &toml::Value) -> Vec<String> {
         .keys()
         .filter(|key| !allowed_author.contains(key.as_str()))
     {
-        warnings.push(format!(
-            "Unknown config key `release_pr.commit_author.{key}` was ignored."
-        ));
+        unknown_keys.push(format!("release_pr.commit_author.{key}"));
     }
 
-    collect_release_pr_nested_warnings(release_pr, warnings)
+    collect_release_pr_nested_unknown_keys(release_pr, unknown_keys)
 }
 
-fn collect_release_pr_nested_warnings(
+fn collect_release_pr_nested_unknown_keys(
     release_pr: &toml::value::Table,
-    mut warnings: Vec<String>,
+    mut unknown_keys: Vec<String>,
 ) -> Vec<String> {
     if let Some(changelog) = release_pr.get("changelog").and_then(toml::Value::as_table) {
-        let allowed_changelog: BTreeSet<&str> = BTreeSet::from(["enabled", "output_file"]);
+        let allowed_changelog: BTreeSet<&str> = BTreeSet::from([
+            "enabled",
+            "output_file",
+            "since_previous_tag",
+            "config_file",
+            "include_types",
+            "tag_template",
+        ]);
         for key in changelog
             .keys()
             .filter(|key| !allowed_changelog.contains(key.as_str()))
         {
-            warnings.push(format!(
-                "Unknown config key `release_pr.changelog.{key}` was ignored."
-            ));
+            unknown_keys.push(format!("release_pr.changelog.{key}"));
+        }
+    }
+
+    if let Some(github) = release_pr.get("github").and_then(toml::Value::as_table) {
+        for key in github.keys().filter(|key| key.as_str() != "use_api") {
+            unknown_keys.push(format!("release_pr.github.{key}"));
         }
     }
 
     if let Some(tagging) = release_pr.get("tagging").and_then(toml::Value::as_table) {
-        let allowed_tagging: BTreeSet<&str> = BTreeSet::from(["enabled", "tag_template"]);
+        let allowed_tagging: BTreeSet<&str> = BTreeSet::from([
+            "enabled",
+            "tag_template",
+            "annotated",
+            "sign",
+            "message_template",
+            "require_annotated_for_baseline",
+        ]);
         for key in tagging
             .keys()
             .filter(|key| !allowed_tagging.contains(key.as_str()))
         {
-            warnings.push(format!(
-                "Unknown config key `release_pr.tagging.{key}` was ignored."
-            ));
+            unknown_keys.push(format!("release_pr.tagging.{key}"));
         }
     }
 
-    warnings
+    if let Some(groups) = release_pr.get("groups").and_then(toml::Value::as_table) {
+        let allowed_group: BTreeSet<&str> = BTreeSet::from([
+            "scopes",
+            "version_updates",
+            "tag_template",
+            "release_branch_pattern",
+            "prerelease_branch_pattern",
+        ]);
+        for (name, group) in groups {
+            let Some(group) = group.as_table() else {
+                continue;
+            };
+            for key in group
+                .keys()
+                .filter(|key| !allowed_group.contains(key.as_str()))
+            {
+                unknown_keys.push(format!("release_pr.groups.{name}.{key}"));
+            }
+        }
+    }
+
+    unknown_keys
 }
 
 fn resolve_config_location(
@@ -564,10 +1689,15 @@ fn resolve_config_location(
         return Ok(Some((explicit.to_path_buf(), true)));
     }
 
-    for candidate in ["brel.toml", ".brel.toml"] {
-        let path = cwd.join(candidate);
-        if path.exists() {
-            return Ok(Some((path, false)));
+    for dir in cwd.ancestors() {
+        for candidate in ["brel.toml", ".brel.toml"] {
+            let path = dir.join(candidate);
+            if path.exists() {
+                return Ok(Some((path, false)));
+            }
+        }
+        if dir.join(".git").exists() {
+            break;
         }
     }
 
@@ -593,6 +1723,28 @@ mod tests {
         assert!(matches!(config.source, ConfigSource::Discovered(_)));
     }
 
+    #[test]
+    fn discovers_config_in_parent_directories_up_to_repo_root() {
+        let temp_dir = tempdir().unwrap();
+        let repo_root = temp_dir.path().join("repo");
+        let nested = repo_root.join("packages/web");
+        fs::create_dir_all(&nested).unwrap();
+        fs::create_dir_all(repo_root.join(".git")).unwrap();
+        fs::write(
+            temp_dir.path().join("brel.toml"),
+            "default_branch = \"outside\"",
+        )
+        .unwrap();
+
+        let config = load(None, &nested).unwrap();
+        assert!(matches!(config.source, ConfigSource::Defaulted));
+
+        fs::write(repo_root.join(".brel.toml"), "default_branch = \"dev\"").unwrap();
+        let config = load(None, &nested).unwrap();
+        assert_eq!(config.default_branch, "dev");
+        assert_eq!(config.source.root_dir(&nested), repo_root.as_path());
+    }
+
     #[test]
     fn explicit_config_path_wins_over_discovery() {
         let temp_dir = tempdir().unwrap();
@@ -653,6 +1805,33 @@ mod tests {
         assert!(err.to_string().contains("Unsupported provider"));
     }
 
+    #[test]
+    fn parses_provider_list() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            "providers = [\"github\", \"GitLab\", \"github\"]",
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(config.provider, Provider::Github);
+        assert_eq!(config.providers, vec![Provider::Github, Provider::Gitlab]);
+
+        fs::write(
+            cwd.join("brel.toml"),
+            "provider = \"github\"\nproviders = [\"gitlab\"]",
+        )
+        .unwrap();
+        let err = load(None, cwd).unwrap_err();
+        assert!(err.to_string().contains("either `provider` or `providers`"));
+
+        fs::write(cwd.join("brel.toml"), "providers = []").unwrap();
+        let err = load(None, cwd).unwrap_err();
+        assert!(err.to_string().contains("`providers` cannot be empty."));
+    }
+
     #[test]
     fn warns_on_unknown_root_keys() {
         let temp_dir = tempdir().unwrap();
@@ -668,6 +1847,103 @@ mod tests {
         assert!(config.warnings[0].contains("experimental"));
     }
 
+    #[test]
+    fn strict_keys_false_suppresses_unknown_key_warnings() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+strict_keys = false
+experimental = true
+
+[release_pr]
+future_option = "x"
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert!(config.warnings.is_empty());
+    }
+
+    #[test]
+    fn strict_keys_true_rejects_unknown_keys() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+strict_keys = true
+experimental = true
+
+[release_pr.tagging]
+future_option = "x"
+"#,
+        )
+        .unwrap();
+
+        let err = load(None, cwd).unwrap_err();
+        assert!(err.to_string().contains("`strict_keys = true`"));
+        assert!(
+            err.to_string()
+                .contains("`experimental`, `release_pr.tagging.future_option`")
+        );
+
+        fs::write(
+            cwd.join("brel.toml"),
+            "strict_keys = true
+",
+        )
+        .unwrap();
+        let config = load(None, cwd).unwrap();
+        assert!(config.warnings.is_empty());
+    }
+
+    #[test]
+    fn parses_per_selector_formats_in_version_updates() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr.version_updates]
+"VERSION.txt" = [{ selector = "version", format = "toml" }, { selector = "pin", format = "anchored" }]
+"package.json" = ["version"]
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(
+            config.release_pr.version_updates["VERSION.txt"],
+            vec!["version".to_string(), "pin".to_string()]
+        );
+        assert_eq!(
+            config.release_pr.selector_formats,
+            BTreeMap::from([(
+                "VERSION.txt".to_string(),
+                BTreeMap::from([
+                    ("pin".to_string(), VersionFileFormat::Anchored),
+                    ("version".to_string(), VersionFileFormat::Toml),
+                ]),
+            )])
+        );
+
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr.version_updates]
+"VERSION.txt" = [{ selector = "version", format = "yaml" }]
+"#,
+        )
+        .unwrap();
+        let err = load(None, cwd).unwrap_err();
+        assert!(format!("{err:#}").contains(
+            "Invalid format for selector `version` in `release_pr.version_updates[\"VERSION.txt\"]`"
+        ));
+    }
+
     #[test]
     fn parses_release_pr_version_update_map() {
         let temp_dir = tempdir().unwrap();
@@ -769,109 +2045,299 @@ output_file = "docs/changelog.md"
     }
 
     #[test]
-    fn parses_release_pr_tagging_settings() {
+    fn parses_changelog_tag_template_separately_from_tagging() {
         let temp_dir = tempdir().unwrap();
         let cwd = temp_dir.path();
         fs::write(
             cwd.join("brel.toml"),
             r#"
-[release_pr.tagging]
-enabled = true
-tag_template = "{{version}}"
+[release_pr.changelog]
+tag_template = "release-{version}"
 "#,
         )
         .unwrap();
 
         let config = load(None, cwd).unwrap();
-        assert!(config.release_pr.tagging.enabled);
-        assert_eq!(config.release_pr.tagging.tag_template, "{version}");
+        assert_eq!(
+            config.release_pr.changelog.tag_template.as_deref(),
+            Some("release-{version}")
+        );
+        assert_eq!(config.release_pr.tagging.tag_template, "v{version}");
+
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr.changelog]
+tag_template = "release"
+"#,
+        )
+        .unwrap();
+        let err = load(None, cwd).unwrap_err();
+        assert!(format!("{err:#}").contains("Invalid `release_pr.changelog.tag_template`."));
     }
 
     #[test]
-    fn rejects_invalid_release_pr_tag_template() {
+    fn validates_commit_trailers() {
         let temp_dir = tempdir().unwrap();
         let cwd = temp_dir.path();
         fs::write(
             cwd.join("brel.toml"),
             r#"
-[release_pr.tagging]
-tag_template = "release"
+[release_pr]
+commit_trailers = [" Refs: RELENG-42 "]
+commit_signoff = true
 "#,
         )
         .unwrap();
 
-        let err = load(None, cwd).unwrap_err();
-        assert!(
-            err.to_string()
-                .contains("Invalid `release_pr.tagging.tag_template`")
-        );
+        let config = load(None, cwd).unwrap();
+        assert_eq!(config.release_pr.commit_trailers, vec!["Refs: RELENG-42"]);
+        assert!(config.release_pr.commit_signoff);
+
+        for trailer in ["Refs RELENG-42", "Co authored: x", "Refs: "] {
+            fs::write(
+                cwd.join("brel.toml"),
+                format!("[release_pr]\ncommit_trailers = [\"{trailer}\"]\n"),
+            )
+            .unwrap();
+            let err = load(None, cwd).unwrap_err();
+            assert!(err.to_string().contains("must look like `Key: value`"));
+        }
     }
 
     #[test]
-    fn rejects_empty_version_selector() {
+    fn parses_release_pr_github_settings() {
         let temp_dir = tempdir().unwrap();
         let cwd = temp_dir.path();
         fs::write(
             cwd.join("brel.toml"),
             r#"
-[release_pr.version_updates]
-"package.json" = [""]
+[release_pr.github]
+use_api = true
 "#,
         )
         .unwrap();
 
-        let err = load(None, cwd).unwrap_err();
-        assert!(err.to_string().contains("Version selector cannot be empty"));
+        let config = load(None, cwd).unwrap();
+        assert!(config.release_pr.github.use_api);
+        assert!(config.warnings.is_empty());
+        assert!(!ReleasePrConfig::default().github.use_api);
     }
 
     #[test]
-    fn rejects_malformed_version_selector() {
+    fn parses_release_pr_tagging_settings() {
         let temp_dir = tempdir().unwrap();
         let cwd = temp_dir.path();
         fs::write(
             cwd.join("brel.toml"),
             r#"
-[release_pr.version_updates]
-"package.json" = ["package[name].version"]
+[release_pr.tagging]
+enabled = true
+tag_template = "{{version}}"
+annotated = true
+sign = true
 "#,
         )
         .unwrap();
 
-        let err = load(None, cwd).unwrap_err();
-        assert!(err.to_string().contains("Invalid version selector"));
+        let config = load(None, cwd).unwrap();
+        assert!(config.release_pr.tagging.enabled);
+        assert_eq!(config.release_pr.tagging.tag_template, "{version}");
+        assert!(config.release_pr.tagging.annotated);
+        assert!(config.release_pr.tagging.sign);
+        assert!(config.warnings.is_empty());
     }
 
     #[test]
-    fn rejects_format_override_without_matching_update_target() {
+    fn parses_tag_message_template() {
         let temp_dir = tempdir().unwrap();
         let cwd = temp_dir.path();
         fs::write(
             cwd.join("brel.toml"),
             r#"
-[release_pr.version_updates]
-"package.json" = ["version"]
-
-[release_pr.format_overrides]
-"Cargo.toml" = "toml"
+[release_pr.tagging]
+enabled = true
+annotated = true
+message_template = "{{tag}} shipped on {date}"
+require_annotated_for_baseline = true
 "#,
         )
         .unwrap();
 
-        let err = load(None, cwd).unwrap_err();
-        assert!(
-            err.to_string()
-                .contains("no matching `release_pr.version_updates` entry")
+        let config = load(None, cwd).unwrap();
+        assert!(config.release_pr.tagging.require_annotated_for_baseline);
+        assert!(config.warnings.is_empty());
+        assert_eq!(
+            config.release_pr.tagging.message_template,
+            "{tag} shipped on {date}"
         );
     }
 
     #[test]
-    fn warns_on_unknown_nested_release_pr_keys() {
+    fn rejects_tag_message_template_with_unknown_token() {
         let temp_dir = tempdir().unwrap();
         let cwd = temp_dir.path();
         fs::write(
             cwd.join("brel.toml"),
             r#"
-[release_pr]
+[release_pr.tagging]
+message_template = "Release {name}"
+"#,
+        )
+        .unwrap();
+
+        let err = load(None, cwd).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Invalid `release_pr.tagging.message_template`")
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_release_pr_tag_template() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr.tagging]
+tag_template = "release"
+"#,
+        )
+        .unwrap();
+
+        let err = load(None, cwd).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Invalid `release_pr.tagging.tag_template`")
+        );
+    }
+
+    #[test]
+    fn rejects_empty_version_selector() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr.version_updates]
+"package.json" = [""]
+"#,
+        )
+        .unwrap();
+
+        let err = load(None, cwd).unwrap_err();
+        assert!(err.to_string().contains("Version selector cannot be empty"));
+    }
+
+    #[test]
+    fn rejects_malformed_version_selector() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr.version_updates]
+"package.json" = ["package[name].version"]
+"#,
+        )
+        .unwrap();
+
+        let err = load(None, cwd).unwrap_err();
+        assert!(err.to_string().contains("Invalid version selector"));
+    }
+
+    #[test]
+    fn accepts_glob_format_override_without_exact_update_target() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr.version_updates]
+"Cargo.lock" = ["package[name=brel].version"]
+
+[release_pr.format_overrides]
+"*.lock" = "toml"
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(
+            config.release_pr.format_overrides.get("*.lock"),
+            Some(&VersionFileFormat::Toml)
+        );
+    }
+
+    #[test]
+    fn rejects_format_override_without_matching_update_target() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr.version_updates]
+"package.json" = ["version"]
+
+[release_pr.format_overrides]
+"Cargo.toml" = "toml"
+"#,
+        )
+        .unwrap();
+
+        let err = load(None, cwd).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("no matching `release_pr.version_updates` entry")
+        );
+    }
+
+    #[test]
+    fn parses_tag_files_and_rejects_untracked_paths() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+tag_files = ["VERSION.md", "docs/*.md"]
+
+[release_pr.version_updates]
+"VERSION.md" = ["version"]
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(config.release_pr.tag_files, vec!["VERSION.md", "docs/*.md"]);
+
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+tag_files = ["CHANGELOG.md"]
+
+[release_pr.version_updates]
+"VERSION.md" = ["version"]
+"#,
+        )
+        .unwrap();
+        let err = load(None, cwd).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("`release_pr.tag_files` includes `CHANGELOG.md`")
+        );
+    }
+
+    #[test]
+    fn warns_on_unknown_nested_release_pr_keys() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
 foo = "bar"
 
 [release_pr.commit_author]
@@ -966,4 +2432,865 @@ release_branch_pattern = "brel/release/{{date}}"
         let err = load(None, cwd).unwrap_err();
         assert!(err.to_string().contains("unsupported token"));
     }
+
+    #[test]
+    fn parses_prerelease_branch_pattern() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        assert_eq!(
+            ReleasePrConfig::default().prerelease_branch_pattern,
+            "brel/prerelease/v{{version}}"
+        );
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+prerelease_branch_pattern = " next/v{{version}} "
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(
+            config.release_pr.prerelease_branch_pattern,
+            "next/v{{version}}"
+        );
+    }
+
+    #[test]
+    fn parses_release_branch_slugify_flag() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+release_branch_slugify = true
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert!(config.release_pr.release_branch_slugify);
+        assert!(config.warnings.is_empty());
+    }
+
+    #[test]
+    fn parses_cleanup_stale_branches_flag() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+cleanup_stale_branches = true
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert!(config.release_pr.cleanup_stale_branches);
+        assert!(!ReleasePrConfig::default().cleanup_stale_branches);
+    }
+
+    #[test]
+    fn run_hooks_defaults_to_false() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        let config = load(None, cwd).unwrap();
+        assert!(!config.release_pr.run_hooks);
+
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+run_hooks = true
+"#,
+        )
+        .unwrap();
+        let config = load(None, cwd).unwrap();
+        assert!(config.release_pr.run_hooks);
+    }
+
+    #[test]
+    fn parses_all_tags_flag() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+all_tags = true
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert!(config.release_pr.all_tags);
+        assert!(config.warnings.is_empty());
+    }
+
+    #[test]
+    fn parses_glob_version_update_keys() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+allow_empty_globs = true
+allow_missing_selectors = true
+strict_single_match = true
+exclude = [" crates/vendor/* "]
+
+[release_pr.version_updates]
+"crates/*/Cargo.toml" = ["package.version"]
+
+[release_pr.format_overrides]
+"crates/core/Cargo.toml" = "toml"
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert!(config.release_pr.allow_empty_globs);
+        assert!(config.release_pr.allow_missing_selectors);
+        assert!(config.release_pr.strict_single_match);
+        assert_eq!(config.release_pr.exclude, vec!["crates/vendor/*"]);
+        assert!(
+            config
+                .release_pr
+                .version_updates
+                .contains_key("crates/*/Cargo.toml")
+        );
+        assert!(config.warnings.is_empty());
+    }
+
+    #[test]
+    fn parses_notes_output_file() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+notes_output_file = " .github/brel/RELEASE_NOTES.md "
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(
+            config.release_pr.notes_output_file.as_deref(),
+            Some(".github/brel/RELEASE_NOTES.md")
+        );
+        assert!(config.warnings.is_empty());
+
+        fs::write(
+            cwd.join("brel.toml"),
+            "[release_pr]\nnotes_output_file = \"../NOTES.md\"\n",
+        )
+        .unwrap();
+        let err = load(None, cwd).unwrap_err();
+        assert!(err.to_string().contains("cannot contain `..`"));
+    }
+
+    #[test]
+    fn parses_and_flattens_type_aliases() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr.type_aliases]
+Feature = "feat"
+bugfix = "FIX"
+bug = "bugfix"
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(
+            config.release_pr.type_aliases,
+            BTreeMap::from([
+                ("bug".to_string(), "fix".to_string()),
+                ("bugfix".to_string(), "fix".to_string()),
+                ("feature".to_string(), "feat".to_string()),
+            ])
+        );
+        assert!(config.warnings.is_empty());
+    }
+
+    #[test]
+    fn rejects_type_alias_cycles() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr.type_aliases]
+feature = "feat"
+feat = "feature"
+"#,
+        )
+        .unwrap();
+
+        let err = load(None, cwd).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("contains a cycle: `feat` -> `feature` -> `feat`")
+        );
+    }
+
+    #[test]
+    fn parses_include_prereleases_flag() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+include_prereleases = true
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert!(config.release_pr.include_prereleases);
+        assert!(config.warnings.is_empty());
+    }
+
+    #[test]
+    fn parses_lock_settings() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+lock = true
+lock_timeout_secs = 600
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert!(config.release_pr.lock);
+        assert_eq!(config.release_pr.lock_timeout_secs, 600);
+        assert!(config.warnings.is_empty());
+    }
+
+    #[test]
+    fn rejects_zero_lock_timeout() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+lock = true
+lock_timeout_secs = 0
+"#,
+        )
+        .unwrap();
+
+        let err = load(None, cwd).unwrap_err();
+        assert!(format!("{err:#}").contains("lock_timeout_secs"));
+    }
+
+    #[test]
+    fn parses_pr_title_template() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+pr_title_template = "Release {{version}} ({{counts.feat}} features)"
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(
+            config.release_pr.pr_title_template.as_deref(),
+            Some("Release {{version}} ({{counts.feat}} features)")
+        );
+        assert!(config.warnings.is_empty());
+    }
+
+    #[test]
+    fn warns_when_pr_title_template_is_combined_with_tagging() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+pr_title_template = "Ship {{tag}}"
+
+[release_pr.tagging]
+enabled = true
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(config.warnings.len(), 1);
+        assert!(config.warnings[0].contains("pr_title_template"));
+    }
+
+    #[test]
+    fn parses_release_as_footer() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+release_as_footer = "Release-As"
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(
+            config.release_pr.release_as_footer.as_deref(),
+            Some("Release-As")
+        );
+
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+release_as_footer = "Release As:"
+"#,
+        )
+        .unwrap();
+        let err = load(None, cwd).unwrap_err();
+        assert!(err.to_string().contains("release_as_footer"));
+    }
+
+    #[test]
+    fn parses_minimum_bump() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+minimum_bump = "Minor"
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(config.release_pr.minimum_bump, Some(BumpLevel::Minor));
+        assert_eq!(config.release_pr.release_threshold, None);
+
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+release_threshold = "minor"
+"#,
+        )
+        .unwrap();
+        let config = load(None, cwd).unwrap();
+        assert_eq!(config.release_pr.release_threshold, Some(BumpLevel::Minor));
+
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+minimum_bump = "huge"
+"#,
+        )
+        .unwrap();
+        let err = load(None, cwd).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Invalid `release_pr.minimum_bump`")
+        );
+    }
+
+    #[test]
+    fn parses_trailer_bump_flag() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+trailer_bump = true
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert!(config.release_pr.trailer_bump);
+        assert!(!ReleasePrConfig::default().trailer_bump);
+    }
+
+    #[test]
+    fn parses_breaking_change_as() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+breaking_change_as = "patch"
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(config.release_pr.breaking_change_as, BumpLevel::Patch);
+        assert_eq!(
+            ReleasePrConfig::default().breaking_change_as,
+            BumpLevel::Major
+        );
+
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+breaking_change_as = "none"
+"#,
+        )
+        .unwrap();
+        let err = load(None, cwd).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Invalid `release_pr.breaking_change_as`")
+        );
+    }
+
+    #[test]
+    fn profile_overrides_base_release_pr_settings() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+default_branch = "main"
+
+[release_pr]
+release_branch_pattern = "brel/release/v{{version}}"
+pr_title_template = "Release {{tag}}"
+
+[release_pr.tagging]
+enabled = true
+tag_template = "v{version}"
+
+[profile.staging]
+default_branch = "staging"
+
+[profile.staging.release_pr]
+release_branch_pattern = "brel/staging/v{{version}}"
+
+[profile.staging.release_pr.tagging]
+tag_template = "staging-v{version}"
+
+[profile.production.release_pr]
+pr_title_template = "Production release {{tag}}"
+"#,
+        )
+        .unwrap();
+
+        let base = load(None, cwd).unwrap();
+        assert_eq!(base.default_branch, "main");
+        assert_eq!(base.profile, None);
+
+        let staging = load_with_profile(None, cwd, Some("staging")).unwrap();
+        assert_eq!(staging.profile.as_deref(), Some("staging"));
+        assert_eq!(staging.default_branch, "staging");
+        assert_eq!(
+            staging.release_pr.release_branch_pattern,
+            "brel/staging/v{{version}}"
+        );
+        assert_eq!(
+            staging.release_pr.pr_title_template.as_deref(),
+            Some("Release {{tag}}")
+        );
+        assert!(staging.release_pr.tagging.enabled);
+        assert_eq!(
+            staging.release_pr.tagging.tag_template,
+            "staging-v{version}"
+        );
+
+        let production = load_with_profile(None, cwd, Some("production")).unwrap();
+        assert_eq!(production.default_branch, "main");
+        assert_eq!(
+            production.release_pr.release_branch_pattern,
+            "brel/release/v{{version}}"
+        );
+        assert_eq!(
+            production.release_pr.pr_title_template.as_deref(),
+            Some("Production release {{tag}}")
+        );
+
+        let err = load_with_profile(None, cwd, Some("qa")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown profile `qa`. Available profiles: `production`, `staging`."
+        );
+    }
+
+    #[test]
+    fn profile_requires_a_config_file() {
+        let temp_dir = tempdir().unwrap();
+        let err = load_with_profile(None, temp_dir.path(), Some("staging")).unwrap_err();
+        assert!(err.to_string().contains("Unknown profile `staging`"));
+    }
+
+    #[test]
+    fn parses_version_source() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+version_source = "file:packages/app/package.json:version"
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(
+            config.release_pr.version_source,
+            VersionSource::File {
+                path: "packages/app/package.json".to_string(),
+                selector: "version".to_string(),
+            }
+        );
+        assert_eq!(
+            config.release_pr.version_source.to_string(),
+            "file:packages/app/package.json:version"
+        );
+        assert_eq!(
+            ReleasePrConfig::default().version_source,
+            VersionSource::Tags
+        );
+        assert_eq!(
+            "tags".parse::<VersionSource>().unwrap(),
+            VersionSource::Tags
+        );
+        assert!("file:package.json".parse::<VersionSource>().is_err());
+        assert!("git".parse::<VersionSource>().is_err());
+    }
+
+    #[test]
+    fn parses_commit_format() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+commit_format = "Bracketed"
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(config.release_pr.commit_format, CommitFormat::Bracketed);
+        assert_eq!(
+            ReleasePrConfig::default().commit_format,
+            CommitFormat::Conventional
+        );
+
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+commit_format = "gitmoji"
+"#,
+        )
+        .unwrap();
+        let err = load(None, cwd).unwrap_err();
+        assert!(format!("{err:#}").contains("Expected `conventional` or `bracketed`"));
+    }
+
+    #[test]
+    fn tag_files_are_checked_against_group_version_updates() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+tag_files = ["docs/VERSION"]
+
+[release_pr.groups.app]
+scopes = ["app"]
+version_updates = { "package.json" = ["version"] }
+
+[release_pr.groups.docs]
+scopes = ["docs"]
+version_updates = { "docs/VERSION" = ["version"] }
+"#,
+        )
+        .unwrap();
+        let config = load(None, cwd).unwrap();
+        assert_eq!(config.release_pr.tag_files, vec!["docs/VERSION"]);
+
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+tag_files = ["VERSION"]
+
+[release_pr.version_updates]
+"VERSION" = ["version"]
+
+[release_pr.groups.app]
+scopes = ["app"]
+version_updates = { "package.json" = ["version"] }
+"#,
+        )
+        .unwrap();
+        let err = load(None, cwd).unwrap_err();
+        assert!(err.to_string().contains(
+            "`release_pr.tag_files` includes `VERSION`, but no \
+             `release_pr.groups.<name>.version_updates` entry lists it."
+        ));
+    }
+
+    #[test]
+    fn parses_release_groups_and_narrows_config_per_group() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr.groups.app]
+scopes = ["App"]
+version_updates = { "package.json" = ["version"] }
+
+[release_pr.groups.docs]
+scopes = ["docs"]
+version_updates = { "docs/package.json" = ["version"] }
+tag_template = "docs@{version}"
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        let app = &config.release_pr.groups["app"];
+        assert_eq!(app.scopes, vec!["app"]);
+        assert_eq!(app.tag_template, "app-v{version}");
+        assert_eq!(app.release_branch_pattern, "brel/release/app/v{{version}}");
+        assert!(app.version_updates.contains_key("package.json"));
+
+        let docs = config.for_group("docs").unwrap();
+        assert_eq!(docs.release_pr.tagging.tag_template, "docs@{version}");
+        assert_eq!(docs.release_pr.scopes, vec!["docs"]);
+        let app = config.for_group("app").unwrap();
+        assert_eq!(
+            app.release_pr.prerelease_branch_pattern,
+            "brel/prerelease/app/v{{version}}"
+        );
+        assert_eq!(
+            docs.release_pr.prerelease_branch_pattern,
+            "brel/prerelease/docs/v{{version}}"
+        );
+        assert_ne!(
+            app.release_pr.prerelease_branch_pattern,
+            docs.release_pr.prerelease_branch_pattern
+        );
+        assert_eq!(
+            docs.release_pr.managed_marker,
+            "managed-by: brel group:docs"
+        );
+        assert!(docs.release_pr.groups.is_empty());
+        assert!(config.for_group("missing").is_none());
+
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr.groups."my app"]
+scopes = ["app"]
+version_updates = { "package.json" = ["version"] }
+"#,
+        )
+        .unwrap();
+        let err = load(None, cwd).unwrap_err();
+        assert!(err.to_string().contains("may only contain ASCII letters"));
+
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr.tagging]
+enabled = true
+
+[release_pr.groups.app]
+scopes = ["app"]
+version_updates = { "package.json" = ["version"] }
+"#,
+        )
+        .unwrap();
+        let err = load(None, cwd).unwrap_err();
+        assert!(err.to_string().contains(
+            "`release_pr.groups` cannot be combined with `release_pr.tagging.enabled = true`"
+        ));
+    }
+
+    #[test]
+    fn parses_issue_pattern_and_rejects_invalid_regex() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+issue_pattern = '[A-Z][A-Z0-9]+-\d+'
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(config.release_pr.issue_pattern, r"[A-Z][A-Z0-9]+-\d+");
+        assert_eq!(
+            ReleasePrConfig::default().issue_pattern,
+            DEFAULT_ISSUE_PATTERN
+        );
+
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+issue_pattern = "(#"
+"#,
+        )
+        .unwrap();
+        let err = load(None, cwd).unwrap_err();
+        assert!(format!("{err:#}").contains("Invalid `release_pr.issue_pattern`"));
+    }
+
+    #[test]
+    fn parses_max_body_commits() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+max_body_commits = 50
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(config.release_pr.max_body_commits, Some(50));
+        assert_eq!(ReleasePrConfig::default().max_body_commits, None);
+
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+max_body_commits = 0
+"#,
+        )
+        .unwrap();
+        let err = load(None, cwd).unwrap_err();
+        assert!(format!("{err:#}").contains("`release_pr.max_body_commits` must be greater"));
+    }
+
+    #[test]
+    fn parses_managed_marker_and_rejects_unsafe_characters() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+managed_marker = " managed-by: acme-release "
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(config.release_pr.managed_marker, "managed-by: acme-release");
+        assert_eq!(
+            ReleasePrConfig::default().managed_marker,
+            DEFAULT_MANAGED_MARKER
+        );
+
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+managed_marker = "managed --> by"
+"#,
+        )
+        .unwrap();
+        let err = load(None, cwd).unwrap_err();
+        assert!(format!("{err:#}").contains("`release_pr.managed_marker` may only contain"));
+    }
+
+    #[test]
+    fn parses_non_utf8_commit_policy() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+non_utf8_commits = "Skip"
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(
+            config.release_pr.non_utf8_commits,
+            NonUtf8CommitPolicy::Skip
+        );
+
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+non_utf8_commits = "drop"
+"#,
+        )
+        .unwrap();
+        let err = load(None, cwd).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Invalid `release_pr.non_utf8_commits`")
+        );
+    }
+
+    #[test]
+    fn parses_release_mode() {
+        let temp_dir = tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+mode = "Direct"
+"#,
+        )
+        .unwrap();
+
+        let config = load(None, cwd).unwrap();
+        assert_eq!(config.release_pr.mode, ReleaseMode::Direct);
+
+        fs::write(
+            cwd.join("brel.toml"),
+            r#"
+[release_pr]
+mode = "push"
+"#,
+        )
+        .unwrap();
+        let err = load(None, cwd).unwrap_err();
+        assert!(err.to_string().contains("Invalid `release_pr.mode`"));
+    }
 }
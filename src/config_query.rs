@@ -0,0 +1,273 @@
+use crate::cli::{ConfigArgs, ConfigCommands};
+use crate::config::{self, ResolvedConfig};
+use crate::release_pr;
+use anyhow::{Context, Result, bail};
+
+pub const SUPPORTED_KEYS: &[&str] = &[
+    "provider",
+    "default_branch",
+    "workflow_file",
+    "release_pr.allow_empty_globs",
+    "release_pr.allow_missing_selectors",
+    "release_pr.strict_single_match",
+    "release_pr.exclude",
+    "release_pr.release_branch_pattern",
+    "release_pr.prerelease_branch_pattern",
+    "release_pr.release_branch_slugify",
+    "release_pr.cleanup_stale_branches",
+    "release_pr.pr_template_file",
+    "release_pr.pr_title_template",
+    "release_pr.milestone",
+    "release_pr.notes_output_file",
+    "release_pr.post_update_command",
+    "release_pr.commit_body_template",
+    "release_pr.commit_signoff",
+    "release_pr.run_hooks",
+    "release_pr.all_tags",
+    "release_pr.include_prereleases",
+    "release_pr.lock",
+    "release_pr.lock_timeout_secs",
+    "release_pr.release_as_footer",
+    "release_pr.build_metadata_template",
+    "release_pr.minimum_bump",
+    "release_pr.release_threshold",
+    "release_pr.trailer_bump",
+    "release_pr.breaking_change_as",
+    "release_pr.ignore_merges",
+    "release_pr.commit_format",
+    "release_pr.non_utf8_commits",
+    "release_pr.mode",
+    "release_pr.managed_marker",
+    "release_pr.version_source",
+    "release_pr.issue_pattern",
+    "release_pr.max_body_commits",
+    "release_pr.commit_author.name",
+    "release_pr.commit_author.email",
+    "release_pr.changelog.enabled",
+    "release_pr.changelog.output_file",
+    "release_pr.changelog.since_previous_tag",
+    "release_pr.changelog.config_file",
+    "release_pr.changelog.tag_template",
+    "release_pr.changelog.include_types",
+    "release_pr.github.use_api",
+    "release_pr.tagging.enabled",
+    "release_pr.tagging.tag_template",
+    "release_pr.tagging.annotated",
+    "release_pr.tagging.sign",
+    "release_pr.tagging.message_template",
+    "release_pr.tagging.require_annotated_for_baseline",
+];
+
+pub fn run(args: ConfigArgs) -> Result<()> {
+    let (cwd, _) = release_pr::current_dirs()?;
+    match args.command {
+        ConfigCommands::Get(get_args) => {
+            let config = config::load_with_profile(
+                get_args.config.as_deref(),
+                &cwd,
+                get_args.profile.as_deref(),
+            )?;
+            for warning in &config.warnings {
+                eprintln!("warning: {warning}");
+            }
+
+            println!("{}", get_value(&config, &get_args.key)?);
+            Ok(())
+        }
+    }
+}
+
+pub(crate) fn get_value(config: &ResolvedConfig, key: &str) -> Result<String> {
+    let key = key.trim();
+    let release_pr = &config.release_pr;
+    let value = match key {
+        "provider" => Some(config.provider.to_string()),
+        "default_branch" => Some(config.default_branch.clone()),
+        "workflow_file" => Some(config.workflow_file.clone()),
+        "release_pr.allow_empty_globs" => Some(release_pr.allow_empty_globs.to_string()),
+        "release_pr.allow_missing_selectors" => {
+            Some(release_pr.allow_missing_selectors.to_string())
+        }
+        "release_pr.strict_single_match" => Some(release_pr.strict_single_match.to_string()),
+        "release_pr.exclude" => list_value(&release_pr.exclude),
+        "release_pr.release_branch_pattern" => Some(release_pr.release_branch_pattern.clone()),
+        "release_pr.prerelease_branch_pattern" => {
+            Some(release_pr.prerelease_branch_pattern.clone())
+        }
+        "release_pr.release_branch_slugify" => Some(release_pr.release_branch_slugify.to_string()),
+        "release_pr.cleanup_stale_branches" => Some(release_pr.cleanup_stale_branches.to_string()),
+        "release_pr.pr_template_file" => release_pr.pr_template_file.clone(),
+        "release_pr.pr_title_template" => release_pr.pr_title_template.clone(),
+        "release_pr.milestone" => release_pr.milestone.clone(),
+        "release_pr.notes_output_file" => release_pr.notes_output_file.clone(),
+        "release_pr.post_update_command" => release_pr.post_update_command.clone(),
+        "release_pr.commit_body_template" => release_pr.commit_body_template.clone(),
+        "release_pr.commit_signoff" => Some(release_pr.commit_signoff.to_string()),
+        "release_pr.run_hooks" => Some(release_pr.run_hooks.to_string()),
+        "release_pr.all_tags" => Some(release_pr.all_tags.to_string()),
+        "release_pr.include_prereleases" => Some(release_pr.include_prereleases.to_string()),
+        "release_pr.lock" => Some(release_pr.lock.to_string()),
+        "release_pr.lock_timeout_secs" => Some(release_pr.lock_timeout_secs.to_string()),
+        "release_pr.release_as_footer" => release_pr.release_as_footer.clone(),
+        "release_pr.build_metadata_template" => release_pr.build_metadata_template.clone(),
+        "release_pr.minimum_bump" => release_pr.minimum_bump.map(|level| level.to_string()),
+        "release_pr.release_threshold" => {
+            release_pr.release_threshold.map(|level| level.to_string())
+        }
+        "release_pr.trailer_bump" => Some(release_pr.trailer_bump.to_string()),
+        "release_pr.breaking_change_as" => Some(release_pr.breaking_change_as.to_string()),
+        "release_pr.ignore_merges" => Some(release_pr.ignore_merges.to_string()),
+        "release_pr.commit_format" => Some(release_pr.commit_format.to_string()),
+        "release_pr.non_utf8_commits" => Some(release_pr.non_utf8_commits.to_string()),
+        "release_pr.mode" => Some(release_pr.mode.to_string()),
+        "release_pr.managed_marker" => Some(release_pr.managed_marker.clone()),
+        "release_pr.version_source" => Some(release_pr.version_source.to_string()),
+        "release_pr.issue_pattern" => Some(release_pr.issue_pattern.clone()),
+        "release_pr.max_body_commits" => release_pr.max_body_commits.map(|max| max.to_string()),
+        "release_pr.commit_author.name" => Some(release_pr.commit_author.name.clone()),
+        "release_pr.commit_author.email" => Some(release_pr.commit_author.email.clone()),
+        "release_pr.changelog.enabled" => Some(release_pr.changelog.enabled.to_string()),
+        "release_pr.changelog.output_file" => Some(release_pr.changelog.output_file.clone()),
+        "release_pr.changelog.since_previous_tag" => {
+            Some(release_pr.changelog.since_previous_tag.to_string())
+        }
+        "release_pr.changelog.config_file" => release_pr.changelog.config_file.clone(),
+        "release_pr.changelog.tag_template" => release_pr.changelog.tag_template.clone(),
+        "release_pr.changelog.include_types" => list_value(&release_pr.changelog.include_types),
+        "release_pr.github.use_api" => Some(release_pr.github.use_api.to_string()),
+        "release_pr.tagging.enabled" => Some(release_pr.tagging.enabled.to_string()),
+        "release_pr.tagging.tag_template" => Some(release_pr.tagging.tag_template.clone()),
+        "release_pr.tagging.annotated" => Some(release_pr.tagging.annotated.to_string()),
+        "release_pr.tagging.sign" => Some(release_pr.tagging.sign.to_string()),
+        "release_pr.tagging.message_template" => Some(release_pr.tagging.message_template.clone()),
+        "release_pr.tagging.require_annotated_for_baseline" => Some(
+            release_pr
+                .tagging
+                .require_annotated_for_baseline
+                .to_string(),
+        ),
+        _ => bail!(
+            "Unsupported config key `{key}`. Supported keys: {}.",
+            SUPPORTED_KEYS.join(", ")
+        ),
+    };
+
+    value.with_context(|| format!("Config key `{key}` is not set."))
+}
+
+// Lists print one entry per line so scripts can read them with `while read`; empty is unset.
+fn list_value(values: &[String]) -> Option<String> {
+    (!values.is_empty()).then(|| values.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn resolves_scalar_keys_including_defaults() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("brel.toml"),
+            r#"
+default_branch = "trunk"
+
+[release_pr.changelog]
+output_file = "docs/CHANGELOG.md"
+"#,
+        )
+        .unwrap();
+
+        let config = config::load(None, temp_dir.path()).unwrap();
+        assert_eq!(get_value(&config, "default_branch").unwrap(), "trunk");
+        assert_eq!(
+            get_value(&config, "release_pr.changelog.output_file").unwrap(),
+            "docs/CHANGELOG.md"
+        );
+        assert_eq!(
+            get_value(&config, "release_pr.tagging.tag_template").unwrap(),
+            "v{version}"
+        );
+        assert_eq!(
+            get_value(&config, "release_pr.tagging.enabled").unwrap(),
+            "false"
+        );
+    }
+
+    #[test]
+    fn resolves_list_keys_one_entry_per_line() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("brel.toml"),
+            r#"
+[release_pr]
+exclude = ["examples/**", "fixtures/*"]
+
+[release_pr.changelog]
+include_types = ["feat", "fix"]
+"#,
+        )
+        .unwrap();
+
+        let config = config::load(None, temp_dir.path()).unwrap();
+        assert_eq!(
+            get_value(&config, "release_pr.exclude").unwrap(),
+            "examples/**\nfixtures/*"
+        );
+        assert_eq!(
+            get_value(&config, "release_pr.changelog.include_types").unwrap(),
+            "feat\nfix"
+        );
+
+        let config = config::load(None, tempdir().unwrap().path()).unwrap();
+        let err = get_value(&config, "release_pr.exclude").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Config key `release_pr.exclude` is not set."
+        );
+    }
+
+    #[test]
+    fn every_supported_key_is_resolvable() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("brel.toml"),
+            r#"
+[release_pr.tagging]
+require_annotated_for_baseline = true
+"#,
+        )
+        .unwrap();
+
+        let config = config::load(None, temp_dir.path()).unwrap();
+        assert_eq!(
+            get_value(&config, "release_pr.tagging.require_annotated_for_baseline").unwrap(),
+            "true"
+        );
+        for key in SUPPORTED_KEYS {
+            if let Err(err) = get_value(&config, key) {
+                assert!(err.to_string().contains("is not set"), "{key}: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn unset_optional_key_is_an_error() {
+        let temp_dir = tempdir().unwrap();
+        let config = config::load(None, temp_dir.path()).unwrap();
+
+        let err = get_value(&config, "release_pr.pr_template_file").unwrap_err();
+        assert!(err.to_string().contains("is not set"));
+    }
+
+    #[test]
+    fn unknown_key_is_an_error() {
+        let temp_dir = tempdir().unwrap();
+        let config = config::load(None, temp_dir.path()).unwrap();
+
+        let err = get_value(&config, "release_pr.nope").unwrap_err();
+        assert!(err.to_string().contains("Unsupported config key"));
+    }
+}
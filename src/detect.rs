@@ -0,0 +1,384 @@
+use crate::cli::DetectArgs;
+use crate::config::{self, ConfigSource, VersionFileFormat};
+use crate::init::{CliInteractor, Interactor};
+use crate::release_pr;
+use crate::version_update;
+use anyhow::{Context, Result};
+use serde_json::Value as JsonValue;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::Value as TomlValue;
+use toml_edit::{Array, DocumentMut, Item, Table, value};
+
+const ECOSYSTEM_CANDIDATES: &[(&str, &[&str])] = &[
+    ("package.json", &["version"]),
+    (
+        "Cargo.toml",
+        &["package.version", "workspace.package.version"],
+    ),
+    (
+        "pyproject.toml",
+        &["project.version", "tool.poetry.version"],
+    ),
+];
+
+#[derive(Debug, Clone)]
+pub struct DetectOptions {
+    pub config_path: Option<PathBuf>,
+    pub profile: Option<String>,
+    pub yes: bool,
+    pub dry_run: bool,
+    /// Where config discovery starts; defaults to the repository root.
+    pub working_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DetectedVersionFile {
+    pub path: String,
+    pub selector: String,
+}
+
+pub fn run(args: DetectArgs) -> Result<()> {
+    let (working_dir, repo_root) = release_pr::current_dirs()?;
+    let options = DetectOptions {
+        config_path: args.config,
+        profile: args.profile,
+        yes: args.yes,
+        dry_run: args.dry_run,
+        working_dir: Some(working_dir),
+    };
+
+    let mut interactor = CliInteractor;
+    run_with_interactor(&repo_root, &options, &mut interactor)
+}
+
+pub(crate) fn run_with_interactor(
+    repo_root: &Path,
+    options: &DetectOptions,
+    interactor: &mut dyn Interactor,
+) -> Result<()> {
+    let config = config::load_with_profile(
+        options.config_path.as_deref(),
+        options.working_dir.as_deref().unwrap_or(repo_root),
+        options.profile.as_deref(),
+    )?;
+    let repo_root = config.source.root_dir(repo_root);
+    for warning in &config.warnings {
+        eprintln!("warning: {warning}");
+    }
+
+    let detected = detect_version_files(repo_root)?
+        .into_iter()
+        .filter(|file| !config.release_pr.version_updates.contains_key(&file.path))
+        .collect::<Vec<_>>();
+    if detected.is_empty() {
+        println!("No new version files detected.");
+        return Ok(());
+    }
+
+    let mut proposal_document = DocumentMut::new();
+    insert_version_updates(&mut proposal_document, &detected)?;
+    let proposal = proposal_document.to_string();
+    println!("Detected version files:\n{proposal}");
+
+    let (config_path, existing) = match &config.source {
+        ConfigSource::Explicit(path) | ConfigSource::Discovered(path) => {
+            let contents = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read `{}`.", path.display()))?;
+            (path.clone(), contents)
+        }
+        ConfigSource::Defaulted => (repo_root.join("brel.toml"), String::new()),
+    };
+
+    if options.dry_run {
+        println!("Dry run: would update `{}`", config_path.display());
+        return Ok(());
+    }
+
+    if !options.yes && !interactor.confirm_version_updates(&config_path, &proposal)? {
+        println!("Skipped `{}` (update declined).", config_path.display());
+        return Ok(());
+    }
+
+    let mut document = existing
+        .parse::<DocumentMut>()
+        .with_context(|| format!("Config file `{}` is not valid TOML.", config_path.display()))?;
+    insert_version_updates(&mut document, &detected)?;
+    fs::write(&config_path, document.to_string())
+        .with_context(|| format!("Failed to write `{}`.", config_path.display()))?;
+    println!("Updated `{}`", config_path.display());
+    Ok(())
+}
+
+pub(crate) fn detect_version_files(repo_root: &Path) -> Result<Vec<DetectedVersionFile>> {
+    let mut detected = Vec::new();
+    for (relative_path, selectors) in ECOSYSTEM_CANDIDATES {
+        let file_path = repo_root.join(relative_path);
+        if !file_path.is_file() {
+            continue;
+        }
+
+        let format = version_update::detect_file_format(relative_path, None)?;
+        let content = fs::read_to_string(&file_path)
+            .with_context(|| format!("Failed to read `{}`.", file_path.display()))?;
+        let Some(selector) = selectors
+            .iter()
+            .find(|selector| has_string_value(format, &content, selector))
+        else {
+            continue;
+        };
+
+        detected.push(DetectedVersionFile {
+            path: (*relative_path).to_string(),
+            selector: (*selector).to_string(),
+        });
+    }
+
+    Ok(detected)
+}
+
+fn has_string_value(format: VersionFileFormat, content: &str, selector: &str) -> bool {
+    match format {
+        VersionFileFormat::Json => {
+            let Ok(mut current) = serde_json::from_str::<JsonValue>(content) else {
+                return false;
+            };
+            for key in selector.split('.') {
+                let Some(child) = current.get_mut(key).map(JsonValue::take) else {
+                    return false;
+                };
+                current = child;
+            }
+            current.is_string()
+        }
+        VersionFileFormat::Toml => {
+            let Ok(root) = content.parse::<TomlValue>() else {
+                return false;
+            };
+            let mut current = &root;
+            for key in selector.split('.') {
+                let Some(child) = current.get(key) else {
+                    return false;
+                };
+                current = child;
+            }
+            current.is_str()
+        }
+        VersionFileFormat::Anchored => false,
+    }
+}
+
+fn insert_version_updates(
+    document: &mut DocumentMut,
+    detected: &[DetectedVersionFile],
+) -> Result<()> {
+    let release_pr = document
+        .entry("release_pr")
+        .or_insert_with(|| {
+            let mut table = Table::new();
+            table.set_implicit(true);
+            Item::Table(table)
+        })
+        .as_table_mut()
+        .context("Config key `release_pr` must be a table.")?;
+    let version_updates = release_pr
+        .entry("version_updates")
+        .or_insert(Item::Table(Table::new()))
+        .as_table_mut()
+        .context("Config key `release_pr.version_updates` must be a table.")?;
+
+    for file in detected {
+        let mut selectors = Array::new();
+        selectors.push(file.selector.as_str());
+        version_updates.insert(&file.path, value(selectors));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[derive(Default)]
+    struct MockInteractor {
+        answer: bool,
+        confirm_calls: usize,
+    }
+
+    impl Interactor for MockInteractor {
+        fn confirm_overwrite(&mut self, _workflow_path: &Path) -> Result<bool> {
+            Ok(false)
+        }
+
+        fn choose_branch_for_mismatch(
+            &mut self,
+            configured_branch: &str,
+            _repo_default_branch: &str,
+        ) -> Result<String> {
+            Ok(configured_branch.to_string())
+        }
+
+        fn confirm_version_updates(
+            &mut self,
+            _config_path: &Path,
+            _proposal: &str,
+        ) -> Result<bool> {
+            self.confirm_calls += 1;
+            Ok(self.answer)
+        }
+    }
+
+    fn detect_options(yes: bool, dry_run: bool) -> DetectOptions {
+        DetectOptions {
+            config_path: None,
+            profile: None,
+            yes,
+            dry_run,
+            working_dir: None,
+        }
+    }
+
+    #[test]
+    fn detects_cargo_toml_package_version() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n",
+        )
+        .unwrap();
+
+        let detected = detect_version_files(temp_dir.path()).unwrap();
+        assert_eq!(
+            detected,
+            vec![DetectedVersionFile {
+                path: "Cargo.toml".to_string(),
+                selector: "package.version".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn detects_workspace_root_and_skips_files_without_versions() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.package]\nversion = \"2.0.0\"\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{ "name": "demo", "private": true }"#,
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            "[tool.poetry]\nversion = \"1.0.0\"\n",
+        )
+        .unwrap();
+
+        let detected = detect_version_files(temp_dir.path()).unwrap();
+        assert_eq!(
+            detected,
+            vec![
+                DetectedVersionFile {
+                    path: "Cargo.toml".to_string(),
+                    selector: "workspace.package.version".to_string(),
+                },
+                DetectedVersionFile {
+                    path: "pyproject.toml".to_string(),
+                    selector: "tool.poetry.version".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn yes_writes_detected_entries_into_existing_config() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("brel.toml"),
+            "# keep me\ndefault_branch = \"main\"\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{ "name": "demo", "version": "1.0.0" }"#,
+        )
+        .unwrap();
+
+        let mut interactor = MockInteractor::default();
+        run_with_interactor(
+            temp_dir.path(),
+            &detect_options(true, false),
+            &mut interactor,
+        )
+        .unwrap();
+
+        let contents = fs::read_to_string(temp_dir.path().join("brel.toml")).unwrap();
+        assert!(contents.contains("# keep me"));
+        assert!(contents.contains("[release_pr.version_updates]"));
+        assert!(contents.contains("\"package.json\" = [\"version\"]"));
+        assert!(!contents.contains("[release_pr]\n"));
+        assert_eq!(interactor.confirm_calls, 0);
+
+        let config = config::load(None, temp_dir.path()).unwrap();
+        assert_eq!(
+            config.release_pr.version_updates.get("package.json"),
+            Some(&vec!["version".to_string()])
+        );
+    }
+
+    #[test]
+    fn declined_prompt_and_dry_run_do_not_write() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n",
+        )
+        .unwrap();
+
+        let mut interactor = MockInteractor::default();
+        run_with_interactor(
+            temp_dir.path(),
+            &detect_options(false, false),
+            &mut interactor,
+        )
+        .unwrap();
+        assert_eq!(interactor.confirm_calls, 1);
+        assert!(!temp_dir.path().join("brel.toml").exists());
+
+        run_with_interactor(
+            temp_dir.path(),
+            &detect_options(true, true),
+            &mut interactor,
+        )
+        .unwrap();
+        assert!(!temp_dir.path().join("brel.toml").exists());
+    }
+
+    #[test]
+    fn already_configured_files_are_not_proposed_again() {
+        let temp_dir = tempdir().unwrap();
+        let config_contents =
+            "[release_pr.version_updates]\n\"Cargo.toml\" = [\"package.version\"]\n";
+        fs::write(temp_dir.path().join("brel.toml"), config_contents).unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n",
+        )
+        .unwrap();
+
+        let mut interactor = MockInteractor::default();
+        run_with_interactor(
+            temp_dir.path(),
+            &detect_options(true, false),
+            &mut interactor,
+        )
+        .unwrap();
+
+        let contents = fs::read_to_string(temp_dir.path().join("brel.toml")).unwrap();
+        assert_eq!(contents, config_contents);
+    }
+}
@@ -0,0 +1,64 @@
+use thiserror::Error;
+
+/// Failure categories returned by the library entry points.
+///
+/// Messages are the same text the binary prints; match on the variant instead of the string.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum BrelError {
+    /// The config file could not be found, read, parsed, or validated.
+    #[error(transparent)]
+    Config(anyhow::Error),
+    /// A version selector such as `package.version` is malformed.
+    #[error(transparent)]
+    Selector(anyhow::Error),
+    /// A configured version update file does not exist.
+    #[error("Configured version update file `{path}` was not found.")]
+    MissingFile { path: String },
+    /// A version file could not be read, parsed, or updated.
+    #[error(transparent)]
+    VersionUpdate(anyhow::Error),
+}
+
+impl BrelError {
+    /// Keeps a typed error raised further down, otherwise files `err` under `kind`.
+    pub(crate) fn classify(err: anyhow::Error, kind: fn(anyhow::Error) -> Self) -> Self {
+        if err.chain().count() == 1 {
+            return err.downcast::<Self>().unwrap_or_else(kind);
+        }
+        // Unwrapping an error that carries extra context would drop part of its message, so
+        // keep the whole chain and only take the category from a selector cause. A missing file
+        // carries its path, which is all callers match on.
+        for cause in err.chain() {
+            match cause.downcast_ref::<Self>() {
+                Some(Self::Selector(_)) => return Self::Selector(err),
+                Some(Self::MissingFile { path }) => {
+                    return Self::MissingFile { path: path.clone() };
+                }
+                _ => {}
+            }
+        }
+        kind(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn classify_finds_a_missing_file_below_added_context() {
+        let err = Err::<(), _>(BrelError::MissingFile {
+            path: "package.json".to_string(),
+        })
+        .context("Failed to update the release files.")
+        .unwrap_err();
+
+        let classified = BrelError::classify(err, BrelError::VersionUpdate);
+        assert!(
+            matches!(&classified, BrelError::MissingFile { path } if path == "package.json"),
+            "{classified:?}"
+        );
+    }
+}
@@ -0,0 +1,392 @@
+use crate::release_pr::GhPullRequest;
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Debug, Clone)]
+pub(crate) struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+    /// The raw `Link` header, which carries the pagination URLs.
+    pub link: Option<String>,
+}
+
+pub(crate) trait HttpClient {
+    fn send(
+        &mut self,
+        method: &str,
+        url: &str,
+        token: &str,
+        body: Option<&str>,
+    ) -> Result<HttpResponse>;
+}
+
+pub(crate) struct UreqClient;
+
+impl HttpClient for UreqClient {
+    fn send(
+        &mut self,
+        method: &str,
+        url: &str,
+        token: &str,
+        body: Option<&str>,
+    ) -> Result<HttpResponse> {
+        let agent = ureq::Agent::new_with_config(
+            ureq::Agent::config_builder()
+                .http_status_as_error(false)
+                .build(),
+        );
+        let authorization = format!("Bearer {token}");
+        let response = match method {
+            "GET" => agent
+                .get(url)
+                .header("Accept", "application/vnd.github+json")
+                .header("Authorization", &authorization)
+                .header("User-Agent", "brel")
+                .call(),
+            "POST" | "PATCH" => {
+                let request = if method == "POST" {
+                    agent.post(url)
+                } else {
+                    agent.patch(url)
+                };
+                request
+                    .header("Accept", "application/vnd.github+json")
+                    .header("Authorization", &authorization)
+                    .header("User-Agent", "brel")
+                    .header("Content-Type", "application/json")
+                    .send(body.unwrap_or_default())
+            }
+            other => bail!("Unsupported HTTP method `{other}`."),
+        };
+        let mut response =
+            response.with_context(|| format!("GitHub API request `{method} {url}` failed."))?;
+
+        let link = response
+            .headers()
+            .get("link")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        Ok(HttpResponse {
+            status: response.status().as_u16(),
+            link,
+            body: response
+                .body_mut()
+                .read_to_string()
+                .context("Failed to read GitHub API response body.")?,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiPullRequest {
+    number: u64,
+    head: ApiPullRequestHead,
+    body: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiPullRequestHead {
+    #[serde(rename = "ref")]
+    ref_name: String,
+}
+
+pub(crate) struct GithubApi<'a> {
+    http: &'a mut dyn HttpClient,
+    token: &'a str,
+    repo_api_url: String,
+}
+
+impl<'a> GithubApi<'a> {
+    /// Builds a client for the repository behind a web URL such as `https://github.com/acme/demo`.
+    pub(crate) fn for_repo_web_url(
+        http: &'a mut dyn HttpClient,
+        token: &'a str,
+        repo_web_url: &str,
+    ) -> Result<Self> {
+        let Some((host, repo_path)) = repo_web_url
+            .strip_prefix("https://")
+            .and_then(|rest| rest.split_once('/'))
+        else {
+            bail!("Cannot derive a GitHub API URL from `{repo_web_url}`.");
+        };
+        let api_base = if host == "github.com" {
+            "https://api.github.com".to_string()
+        } else {
+            format!("https://{host}/api/v3")
+        };
+
+        Ok(Self {
+            http,
+            token,
+            repo_api_url: format!("{api_base}/repos/{repo_path}"),
+        })
+    }
+
+    /// Lists open PRs into `base_branch`, or into any branch when it is `None`.
+    pub(crate) fn list_open_prs(
+        &mut self,
+        base_branch: Option<&str>,
+    ) -> Result<Vec<GhPullRequest>> {
+        let base_filter = base_branch
+            .map(|base| format!("&base={}", encode_query_value(base)))
+            .unwrap_or_default();
+        let mut url = format!(
+            "{}/pulls?state=open{base_filter}&per_page=100",
+            self.repo_api_url
+        );
+        // Repositories with more than a page of open PRs are listed through the `Link` header.
+        let mut prs = Vec::new();
+        loop {
+            let response = self.send("GET", &url, None)?;
+            let page: Vec<ApiPullRequest> = serde_json::from_str(&response.body)
+                .context("Failed to parse GitHub API pull request list.")?;
+            prs.extend(page.into_iter().map(|pr| GhPullRequest {
+                number: pr.number,
+                head_ref_name: pr.head.ref_name,
+                body: pr.body,
+            }));
+            match response.link.as_deref().and_then(next_page_url) {
+                Some(next) => url = next,
+                None => return Ok(prs),
+            }
+        }
+    }
+
+    pub(crate) fn create_pr(
+        &mut self,
+        base_branch: &str,
+        release_branch: &str,
+        title: &str,
+        body: &str,
+    ) -> Result<()> {
+        let url = format!("{}/pulls", self.repo_api_url);
+        let payload = json!({
+            "base": base_branch,
+            "head": release_branch,
+            "title": title,
+            "body": body,
+        });
+        self.request("POST", &url, Some(&payload.to_string()))?;
+        Ok(())
+    }
+
+    pub(crate) fn edit_pr(
+        &mut self,
+        number: u64,
+        base_branch: &str,
+        title: &str,
+        body: &str,
+    ) -> Result<()> {
+        let url = format!("{}/pulls/{number}", self.repo_api_url);
+        let payload = json!({
+            "base": base_branch,
+            "title": title,
+            "body": body,
+        });
+        self.request("PATCH", &url, Some(&payload.to_string()))?;
+        Ok(())
+    }
+
+    fn request(&mut self, method: &str, url: &str, body: Option<&str>) -> Result<String> {
+        Ok(self.send(method, url, body)?.body)
+    }
+
+    fn send(&mut self, method: &str, url: &str, body: Option<&str>) -> Result<HttpResponse> {
+        let response = self.http.send(method, url, self.token, body)?;
+        if !(200..300).contains(&response.status) {
+            bail!(
+                "GitHub API request `{method} {url}` failed (status {}): {}",
+                response.status,
+                response.body.trim()
+            );
+        }
+        Ok(response)
+    }
+}
+
+/// Percent-encodes everything but RFC 3986 unreserved characters, so branch names such as
+/// `release/1.x` or `feat+ci` survive as a single query value.
+fn encode_query_value(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+/// Picks the `rel="next"` URL out of a `Link: <url>; rel="next", <url>; rel="last"` header.
+fn next_page_url(link: &str) -> Option<String> {
+    link.split(',').find_map(|entry| {
+        let (target, params) = entry.split_once(';')?;
+        let is_next = params
+            .split(';')
+            .any(|param| param.trim().replace(' ', "") == "rel=\"next\"");
+        let url = target.trim().strip_prefix('<')?.strip_suffix('>')?;
+        is_next.then(|| url.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    #[derive(Debug, Clone)]
+    struct RecordedRequest {
+        method: String,
+        url: String,
+        token: String,
+        body: Option<String>,
+    }
+
+    struct MockHttpClient {
+        responses: VecDeque<HttpResponse>,
+        requests: Vec<RecordedRequest>,
+    }
+
+    impl MockHttpClient {
+        fn new(responses: Vec<HttpResponse>) -> Self {
+            Self {
+                responses: responses.into(),
+                requests: Vec::new(),
+            }
+        }
+    }
+
+    impl HttpClient for MockHttpClient {
+        fn send(
+            &mut self,
+            method: &str,
+            url: &str,
+            token: &str,
+            body: Option<&str>,
+        ) -> Result<HttpResponse> {
+            self.requests.push(RecordedRequest {
+                method: method.to_string(),
+                url: url.to_string(),
+                token: token.to_string(),
+                body: body.map(str::to_string),
+            });
+            Ok(self.responses.pop_front().expect("unexpected HTTP request"))
+        }
+    }
+
+    fn response(status: u16, body: &str) -> HttpResponse {
+        HttpResponse {
+            status,
+            body: body.to_string(),
+            link: None,
+        }
+    }
+
+    #[test]
+    fn lists_open_prs_for_base_branch() {
+        let mut http = MockHttpClient::new(vec![response(
+            200,
+            r#"[{"number":7,"head":{"ref":"brel/release/v1.2.3"},"body":"managed"}]"#,
+        )]);
+        let mut api =
+            GithubApi::for_repo_web_url(&mut http, "token", "https://github.com/acme/demo")
+                .unwrap();
+
+        let prs = api.list_open_prs(Some("main")).unwrap();
+
+        assert_eq!(prs.len(), 1);
+        assert_eq!(prs[0].number, 7);
+        assert_eq!(prs[0].head_ref_name, "brel/release/v1.2.3");
+        assert_eq!(
+            http.requests[0].url,
+            "https://api.github.com/repos/acme/demo/pulls?state=open&base=main&per_page=100"
+        );
+        assert_eq!(http.requests[0].token, "token");
+    }
+
+    #[test]
+    fn list_open_prs_encodes_base_and_follows_next_links() {
+        let next = "https://api.github.com/repositories/1/pulls?state=open&page=2";
+        let mut http = MockHttpClient::new(vec![
+            HttpResponse {
+                link: Some(format!(
+                    r#"<{next}>; rel="next", <https://api.github.com/repositories/1/pulls?page=2>; rel="last""#
+                )),
+                ..response(200, r#"[{"number":7,"head":{"ref":"a"},"body":null}]"#)
+            },
+            HttpResponse {
+                link: Some(
+                    r#"<https://api.github.com/repositories/1/pulls?page=1>; rel="prev""#
+                        .to_string(),
+                ),
+                ..response(200, r#"[{"number":9,"head":{"ref":"b"},"body":null}]"#)
+            },
+        ]);
+        let mut api =
+            GithubApi::for_repo_web_url(&mut http, "token", "https://github.com/acme/demo")
+                .unwrap();
+
+        let prs = api.list_open_prs(Some("release/1.x+ci")).unwrap();
+
+        assert_eq!(
+            prs.iter().map(|pr| pr.number).collect::<Vec<_>>(),
+            vec![7, 9]
+        );
+        assert_eq!(
+            http.requests[0].url,
+            "https://api.github.com/repos/acme/demo/pulls?state=open&base=release%2F1.x%2Bci&per_page=100"
+        );
+        assert_eq!(http.requests[1].url, next);
+        assert_eq!(http.requests.len(), 2);
+    }
+
+    #[test]
+    fn creates_pr_with_json_payload() {
+        let mut http = MockHttpClient::new(vec![response(201, r#"{"number":8}"#)]);
+        let mut api =
+            GithubApi::for_repo_web_url(&mut http, "token", "https://git.example.com/acme/demo")
+                .unwrap();
+
+        api.create_pr("main", "brel/release/v1.3.0", "Release v1.3.0", "body")
+            .unwrap();
+
+        let request = &http.requests[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(
+            request.url,
+            "https://git.example.com/api/v3/repos/acme/demo/pulls"
+        );
+        let payload: serde_json::Value =
+            serde_json::from_str(request.body.as_deref().unwrap()).unwrap();
+        assert_eq!(payload["head"], "brel/release/v1.3.0");
+        assert_eq!(payload["base"], "main");
+        assert_eq!(payload["title"], "Release v1.3.0");
+    }
+
+    #[test]
+    fn edits_pr_and_reports_api_errors() {
+        let mut http = MockHttpClient::new(vec![
+            response(200, "{}"),
+            response(422, r#"{"message":"Validation Failed"}"#),
+        ]);
+        let mut api =
+            GithubApi::for_repo_web_url(&mut http, "token", "https://github.com/acme/demo")
+                .unwrap();
+
+        api.edit_pr(7, "main", "Release v1.3.0", "new body")
+            .unwrap();
+        let err = api
+            .edit_pr(7, "main", "Release v1.3.0", "new body")
+            .unwrap_err();
+
+        assert!(err.to_string().contains("status 422"));
+        assert!(err.to_string().contains("Validation Failed"));
+        assert_eq!(http.requests[0].method, "PATCH");
+        assert_eq!(
+            http.requests[0].url,
+            "https://api.github.com/repos/acme/demo/pulls/7"
+        );
+    }
+}
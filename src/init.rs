@@ -1,19 +1,27 @@
-use crate::cli::InitArgs;
-use crate::config::{self, ConfigSource, Provider};
+use crate::cli::{InitArgs, OutputFormat};
+use crate::config::{self, ConfigSource, Provider, ResolvedConfig};
+use crate::logging::info;
+use crate::release_pr::{self, CommandRunner, ProcessRunner};
 use crate::tag_template::{self, TagTemplate};
 use crate::template::{self, WorkflowRenderContext, WorkflowTemplate};
 use crate::workflow;
 use anyhow::{Context, Result, bail};
 use dialoguer::{Confirm, Select};
+use serde_json::json;
 use similar::TextDiff;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone)]
 pub struct InitOptions {
     pub config_path: Option<std::path::PathBuf>,
+    pub profile: Option<String>,
     pub yes: bool,
     pub dry_run: bool,
+    pub force: bool,
+    pub format: OutputFormat,
+    /// Where config discovery starts; defaults to the repository root.
+    pub working_dir: Option<std::path::PathBuf>,
 }
 
 pub trait Interactor {
@@ -23,9 +31,10 @@ pub trait Interactor {
         configured_branch: &str,
         repo_default_branch: &str,
     ) -> Result<String>;
+    fn confirm_version_updates(&mut self, config_path: &Path, proposal: &str) -> Result<bool>;
 }
 
-struct CliInteractor;
+pub(crate) struct CliInteractor;
 
 impl Interactor for CliInteractor {
     fn confirm_overwrite(&mut self, workflow_path: &Path) -> Result<bool> {
@@ -63,44 +72,79 @@ impl Interactor for CliInteractor {
             _ => bail!("Invalid branch selection."),
         }
     }
+
+    fn confirm_version_updates(&mut self, config_path: &Path, _proposal: &str) -> Result<bool> {
+        Confirm::new()
+            .with_prompt(format!(
+                "Add detected version files to `{}`?",
+                config_path.display()
+            ))
+            .default(true)
+            .interact()
+            .context("Failed to read version update confirmation.")
+    }
 }
 
 pub fn run(args: InitArgs) -> Result<()> {
+    let (working_dir, repo_root) = release_pr::current_dirs()?;
     let options = InitOptions {
         config_path: args.config,
+        profile: args.profile,
         yes: args.yes,
         dry_run: args.dry_run,
+        force: args.force,
+        format: args.format,
+        working_dir: Some(working_dir),
     };
 
-    let cwd = std::env::current_dir().context("Failed to determine current directory.")?;
     let mut interactor = CliInteractor;
-    run_with_interactor(&cwd, &options, &mut interactor)
+    run_with_interactor(&repo_root, &options, &mut interactor, &mut ProcessRunner)
 }
 
 pub(crate) fn run_with_interactor(
     repo_root: &Path,
     options: &InitOptions,
     interactor: &mut dyn Interactor,
+    runner: &mut dyn CommandRunner,
 ) -> Result<()> {
-    let config = config::load(options.config_path.as_deref(), repo_root)?;
+    let mut config = config::load_with_profile(
+        options.config_path.as_deref(),
+        options.working_dir.as_deref().unwrap_or(repo_root),
+        options.profile.as_deref(),
+    )?;
+    let repo_root = config.source.root_dir(repo_root).to_path_buf();
+    let repo_root = repo_root.as_path();
+    if let Some(warning) = workflow::provider_mismatch_warning(config.provider, repo_root, runner) {
+        config.warnings.push(warning);
+    }
     for warning in &config.warnings {
         eprintln!("warning: {warning}");
     }
 
-    if matches!(config.source, ConfigSource::Defaulted) {
-        print_defaults_summary();
-    } else if let Some(path) = config.source.path() {
-        println!("Loaded config from `{}`", path.display());
+    // JSON output must be the only thing on stdout.
+    let json = options.dry_run && options.format == OutputFormat::Json;
+    if !json {
+        if matches!(config.source, ConfigSource::Defaulted) {
+            print_defaults_summary();
+        } else if let Some(path) = config.source.path() {
+            info!("Loaded config from `{}`", path.display());
+        }
     }
 
-    if config.provider != Provider::Github {
+    // Only GitHub has a workflow template: `brel release-pr` cannot publish to other hosts yet.
+    if let Some(provider) = config
+        .providers
+        .iter()
+        .find(|provider| **provider != Provider::Github)
+    {
         bail!(
-            "Provider `{}` is configured, but `brel init` currently supports only `github`.",
-            config.provider
+            "Provider `{provider}` is configured, but `brel init` currently supports only \
+             `github`: `brel release-pr` cannot publish to `{provider}` yet, so the generated \
+             pipeline would fail on its first release."
         );
     }
 
-    let repo_default_branch = workflow::detect_origin_default_branch(repo_root)?;
+    let repo_default_branch = workflow::detect_default_branch(config.provider, repo_root, runner)?;
     let selected_branch = resolve_default_branch(
         &config.default_branch,
         repo_default_branch.as_deref(),
@@ -108,61 +152,103 @@ pub(crate) fn run_with_interactor(
         interactor,
     )?;
 
-    let workflow_path = workflow::resolve_workflow_path(&config.workflow_file)?;
-    let workflow_absolute_path = repo_root.join(&workflow_path);
-    let release_pr_command = build_release_pr_command(options.config_path.as_deref());
-    let next_version_command = build_next_version_command(options.config_path.as_deref());
-    let next_version_output_expr = "${{ steps.next-version.outputs.version }}";
-    let tag_template = TagTemplate::parse(&config.release_pr.tagging.tag_template)
-        .context("Invalid normalized release tag template.")?;
-    let next_version_tag_output_expr = tag_template.render(next_version_output_expr);
-    let tagging_template_prefix_shell = tag_template::shell_escape_single(tag_template.prefix());
-    let tagging_template_suffix_shell = tag_template::shell_escape_single(tag_template.suffix());
-    let rendered = template::render_workflow(
-        config.provider,
-        WorkflowTemplate::ReleasePr,
-        &WorkflowRenderContext {
-            default_branch: &selected_branch,
-            release_pr_command: &release_pr_command,
-            next_version_command: &next_version_command,
-            github_token_expr: "${{ github.token }}",
-            tagging_push_token_expr: "${{ secrets.BREL_TAG_PUSH_TOKEN }}",
-            next_version_non_empty_expr: "${{ steps.next-version.outputs.version != '' }}",
-            next_version_output_expr,
-            next_version_tag_output_expr: &next_version_tag_output_expr,
-            changelog_enabled: config.release_pr.changelog.enabled,
-            changelog_output_file: &config.release_pr.changelog.output_file,
-            tagging_enabled: config.release_pr.tagging.enabled,
-            tagging_template_prefix_shell: &tagging_template_prefix_shell,
-            tagging_template_suffix_shell: &tagging_template_suffix_shell,
-        },
-    )?;
+    // Plan every file before writing any, so an unmanaged file for one provider leaves the
+    // others untouched.
+    let mut planned = Vec::new();
+    for &provider in &config.providers {
+        let workflow_path = workflow::provider_workflow_path(provider, &config.workflow_file)?;
+        let rendered = render_release_pr_workflow(
+            &config,
+            provider,
+            &selected_branch,
+            options.config_path.as_deref(),
+        )?;
+        let workflow_absolute_path = repo_root.join(&workflow_path);
+        let existing = if workflow_absolute_path.exists() {
+            Some(
+                fs::read_to_string(&workflow_absolute_path)
+                    .with_context(|| format!("Failed to read `{}`.", workflow_path.display()))?,
+            )
+        } else {
+            None
+        };
+        let action = plan_file_action(
+            &workflow_path,
+            existing.as_deref(),
+            &rendered,
+            &config.release_pr.managed_marker,
+            options.yes,
+            options.force,
+            interactor,
+        )?;
+        planned.push(PlannedWorkflow {
+            path: workflow_path,
+            existing,
+            rendered,
+            action,
+        });
+    }
 
-    let existing = if workflow_absolute_path.exists() {
-        Some(
-            fs::read_to_string(&workflow_absolute_path)
-                .with_context(|| format!("Failed to read `{}`.", workflow_path.display()))?,
-        )
-    } else {
-        None
-    };
+    if json {
+        // One object per line, in `providers` order.
+        for workflow in &planned {
+            let before = workflow.existing.as_deref().unwrap_or_default();
+            let (action, diff) = match workflow.action {
+                FileAction::Skip(_) => ("skip", String::new()),
+                FileAction::Create => ("create", unified_diff("", &workflow.rendered)),
+                FileAction::ReplaceUnmanaged | FileAction::Overwrite => {
+                    ("overwrite", unified_diff(before, &workflow.rendered))
+                }
+            };
+            println!(
+                "{}",
+                json!({
+                    "path": workflow.path.display().to_string(),
+                    "action": action,
+                    "diff": diff,
+                })
+            );
+        }
+        return Ok(());
+    }
 
-    let action = plan_file_action(
-        &workflow_path,
-        existing.as_deref(),
-        &rendered,
-        options.yes,
-        interactor,
-    )?;
+    for workflow in &planned {
+        apply_planned_workflow(repo_root, workflow, options.dry_run)?;
+    }
+
+    if config.release_pr.tagging.enabled {
+        print_tagging_token_notice();
+        if config.release_pr.tagging.sign {
+            print_tag_signing_notice(&config.release_pr.commit_author.email);
+        }
+    }
 
-    match action {
+    Ok(())
+}
+
+struct PlannedWorkflow {
+    path: PathBuf,
+    existing: Option<String>,
+    rendered: String,
+    action: FileAction,
+}
+
+fn apply_planned_workflow(
+    repo_root: &Path,
+    workflow: &PlannedWorkflow,
+    dry_run: bool,
+) -> Result<()> {
+    let workflow_path = &workflow.path;
+    let workflow_absolute_path = repo_root.join(workflow_path);
+    let rendered = &workflow.rendered;
+    match workflow.action {
         FileAction::Skip(reason) => {
-            println!("Skipped `{}` ({reason}).", workflow_path.display());
+            info!("Skipped `{}` ({reason}).", workflow_path.display());
         }
         FileAction::Create => {
-            if options.dry_run {
+            if dry_run {
                 println!("Dry run: would create `{}`", workflow_path.display());
-                print_diff("", &rendered);
+                print_diff("", rendered);
             } else {
                 if let Some(parent) = workflow_absolute_path.parent() {
                     fs::create_dir_all(parent).with_context(|| {
@@ -174,33 +260,161 @@ pub(crate) fn run_with_interactor(
                 }
                 fs::write(&workflow_absolute_path, rendered)
                     .with_context(|| format!("Failed to write `{}`.", workflow_path.display()))?;
-                println!("Created `{}`", workflow_path.display());
+                info!("Created `{}`", workflow_path.display());
+            }
+        }
+        FileAction::ReplaceUnmanaged => {
+            let before = workflow.existing.as_deref().unwrap_or_default();
+            eprintln!(
+                "warning: `{}` is not managed by brel; `--force` replaces it with the generated \
+                 workflow. Review the diff below.",
+                workflow_path.display()
+            );
+            print_diff(before, rendered);
+            if dry_run {
+                println!("Dry run: would overwrite `{}`", workflow_path.display());
+            } else {
+                fs::write(&workflow_absolute_path, rendered)
+                    .with_context(|| format!("Failed to write `{}`.", workflow_path.display()))?;
+                info!("Replaced `{}`", workflow_path.display());
             }
         }
         FileAction::Overwrite => {
-            let before = existing.as_deref().unwrap_or_default();
-            if options.dry_run {
+            let before = workflow.existing.as_deref().unwrap_or_default();
+            if dry_run {
                 println!("Dry run: would overwrite `{}`", workflow_path.display());
-                print_diff(before, &rendered);
+                print_diff(before, rendered);
             } else {
                 fs::write(&workflow_absolute_path, rendered)
                     .with_context(|| format!("Failed to write `{}`.", workflow_path.display()))?;
-                println!("Updated `{}`", workflow_path.display());
+                info!("Updated `{}`", workflow_path.display());
             }
         }
+    }
+    Ok(())
+}
+
+pub(crate) fn render_release_pr_workflow(
+    config: &ResolvedConfig,
+    provider: Provider,
+    default_branch: &str,
+    config_path: Option<&Path>,
+) -> Result<String> {
+    let release_pr_command =
+        build_brel_command("release-pr", config_path, config.profile.as_deref());
+    let next_version_command =
+        build_brel_command("next-version", config_path, config.profile.as_deref());
+    let next_version_output_expr = "${{ steps.next-version.outputs.version }}";
+    // git-cliff takes `<tag>..HEAD` as an explicit range; the first release has no tag to start
+    // from and falls back to `--unreleased`.
+    let since_previous_tag = config.release_pr.changelog.since_previous_tag;
+    let previous_tag_command =
+        since_previous_tag.then(|| format!("{next_version_command} --previous-tag"));
+    let changelog_range_arg = if since_previous_tag {
+        "${{ steps.next-version.outputs.previous_tag != '' && format('{0}..HEAD', steps.next-version.outputs.previous_tag) || '--unreleased' }}"
+    } else {
+        "--unreleased"
     };
+    let tag_template = TagTemplate::parse(&config.release_pr.tagging.tag_template)
+        .context("Invalid normalized release tag template.")?;
+    // git-cliff may head the changelog with a different tag than the one pushed to git.
+    let changelog_tag_template = match &config.release_pr.changelog.tag_template {
+        Some(template) => {
+            TagTemplate::parse(template).context("Invalid normalized changelog tag template.")?
+        }
+        None => tag_template.clone(),
+    };
+    let next_version_tag_output_expr = changelog_tag_template.render(next_version_output_expr);
+    let tagging_template_prefix_shell = tag_template::shell_escape_single(tag_template.prefix());
+    let tagging_template_suffix_shell = tag_template::shell_escape_single(tag_template.suffix());
+    let tagger_name_shell =
+        tag_template::shell_escape_single(&config.release_pr.commit_author.name);
+    let tagger_email_shell =
+        tag_template::shell_escape_single(&config.release_pr.commit_author.email);
+    let tagging_message_shell =
+        tag_template::tag_message_shell_expr(&config.release_pr.tagging.message_template);
+    let tagging_build_metadata_shell = config
+        .release_pr
+        .build_metadata_template
+        .as_deref()
+        .map(tag_template::build_metadata_shell_expr);
+    template::render_workflow(
+        provider,
+        WorkflowTemplate::ReleasePr,
+        &WorkflowRenderContext {
+            managed_marker: &config.release_pr.managed_marker,
+            default_branch,
+            release_pr_command: &release_pr_command,
+            next_version_command: &next_version_command,
+            github_token_expr: "${{ github.token }}",
+            tagging_push_token_expr: "${{ secrets.BREL_TAG_PUSH_TOKEN }}",
+            next_version_non_empty_expr: "${{ steps.next-version.outputs.version != '' }}",
+            next_version_output_expr,
+            next_version_tag_output_expr: &next_version_tag_output_expr,
+            next_version_bump_output_expr: "${{ steps.next-version.outputs.bump }}",
+            previous_tag_command: previous_tag_command.as_deref(),
+            changelog_enabled: config.release_pr.changelog.enabled,
+            changelog_range_arg,
+            changelog_config_file: config.release_pr.changelog.config_file.as_deref(),
+            changelog_output_file: &config.release_pr.changelog.output_file,
+            tagging_enabled: config.release_pr.tagging.enabled,
+            tagging_template_prefix_shell: &tagging_template_prefix_shell,
+            tagging_template_suffix_shell: &tagging_template_suffix_shell,
+            tagging_annotated: config.release_pr.tagging.annotated,
+            tagging_sign: config.release_pr.tagging.sign,
+            tagger_name_shell: &tagger_name_shell,
+            tagger_email_shell: &tagger_email_shell,
+            tagging_message_shell: &tagging_message_shell,
+            tagging_build_metadata_shell: tagging_build_metadata_shell.as_deref(),
+            tagging_gpg_key_expr: "${{ secrets.BREL_TAG_GPG_PRIVATE_KEY }}",
+            tagging_gpg_passphrase_expr: "${{ secrets.BREL_TAG_GPG_PASSPHRASE }}",
+        },
+    )
+}
 
-    if config.release_pr.tagging.enabled {
-        print_tagging_token_notice();
+pub(crate) fn find_outdated_workflow(
+    repo_root: &Path,
+    config: &ResolvedConfig,
+    config_path: Option<&Path>,
+) -> Result<Option<PathBuf>> {
+    for &provider in &config.providers {
+        if provider != Provider::Github {
+            continue;
+        }
+        let workflow_path = workflow::provider_workflow_path(provider, &config.workflow_file)?;
+        let Ok(existing) = fs::read_to_string(repo_root.join(&workflow_path)) else {
+            continue;
+        };
+        if !workflow::is_managed(&existing, &config.release_pr.managed_marker) {
+            continue;
+        }
+
+        // `brel init` may have written a branch other than `default_branch` (detected from the
+        // remote or picked at the prompt), so compare against the branch the file triggers on.
+        let branch = workflow_trigger_branch(&existing).unwrap_or(&config.default_branch);
+        let rendered = render_release_pr_workflow(config, provider, branch, config_path)?;
+        if existing != rendered {
+            return Ok(Some(workflow_path));
+        }
     }
+    Ok(None)
+}
 
-    Ok(())
+/// Reads the branch under `on.push.branches` from a generated GitHub workflow.
+fn workflow_trigger_branch(workflow: &str) -> Option<&str> {
+    let mut lines = workflow.lines().skip_while(|line| *line != "  push:");
+    lines.next()?;
+    if lines.next()? != "    branches:" {
+        return None;
+    }
+    lines.next()?.strip_prefix("      - ")
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum FileAction {
     Create,
     Overwrite,
+    ReplaceUnmanaged,
     Skip(&'static str),
 }
 
@@ -208,17 +422,23 @@ fn plan_file_action(
     workflow_path: &Path,
     existing: Option<&str>,
     rendered: &str,
+    managed_marker: &str,
     yes: bool,
+    force: bool,
     interactor: &mut dyn Interactor,
 ) -> Result<FileAction> {
     let Some(existing_content) = existing else {
         return Ok(FileAction::Create);
     };
 
-    if !workflow::is_managed(existing_content) {
+    if !workflow::is_managed(existing_content, managed_marker) {
+        if force && yes {
+            return Ok(FileAction::ReplaceUnmanaged);
+        }
         bail!(
             "Refusing to overwrite unmanaged workflow `{}`. \
-             Move/remove the file or set `workflow_file` to a different filename.",
+             Move/remove the file, set `workflow_file` to a different filename, or pass \
+             `--force --yes` to replace it.",
             workflow_path.display()
         );
     }
@@ -268,74 +488,70 @@ pub(crate) fn resolve_default_branch(
         );
     }
 
-    println!("Using branch `{selected}` for generated workflow triggers.");
+    info!("Using branch `{selected}` for generated workflow triggers.");
     Ok(selected)
 }
 
 fn print_defaults_summary() {
-    println!("No config file found. Using defaults:");
-    println!("  provider: github");
-    println!("  default_branch: main");
-    println!("  workflow_file: release-pr.yml");
+    info!("No config file found. Using defaults:");
+    info!("  provider: github");
+    info!("  default_branch: main");
+    info!("  workflow_file: release-pr.yml");
 }
 
 fn print_tagging_token_notice() {
-    println!(
+    info!(
         "Tagging is enabled. Add repository secret `BREL_TAG_PUSH_TOKEN` \
          (PAT with Contents: Read and write)."
     );
-    println!(
+    info!(
         "Without this token, tags pushed by the workflow will not trigger \
          downstream tag-push workflows."
     );
 }
 
-fn build_release_pr_command(explicit_config_path: Option<&Path>) -> String {
-    let Some(path) = explicit_config_path else {
-        return "brel release-pr".to_string();
-    };
-
-    let file_name = path
-        .file_name()
-        .and_then(|value| value.to_str())
-        .unwrap_or("");
-    if file_name == "brel.toml" || file_name == ".brel.toml" {
-        return "brel release-pr".to_string();
-    }
-
-    format!(
-        "brel release-pr --config {}",
-        tag_template::shell_escape_single(path.to_string_lossy().as_ref())
-    )
+fn print_tag_signing_notice(tagger_email: &str) {
+    info!(
+        "Tag signing is enabled. Add repository secret `BREL_TAG_GPG_PRIVATE_KEY` \
+         (GPG key for `{tagger_email}`) and, if the key has one, `BREL_TAG_GPG_PASSPHRASE`."
+    );
 }
 
-fn build_next_version_command(explicit_config_path: Option<&Path>) -> String {
-    let Some(path) = explicit_config_path else {
-        return "brel next-version".to_string();
-    };
-
-    let file_name = path
-        .file_name()
-        .and_then(|value| value.to_str())
-        .unwrap_or("");
-    if file_name == "brel.toml" || file_name == ".brel.toml" {
-        return "brel next-version".to_string();
+fn build_brel_command(
+    subcommand: &str,
+    explicit_config_path: Option<&Path>,
+    profile: Option<&str>,
+) -> String {
+    let mut command = format!("brel {subcommand}");
+    if let Some(path) = explicit_config_path {
+        let file_name = path
+            .file_name()
+            .and_then(|value| value.to_str())
+            .unwrap_or("");
+        if file_name != "brel.toml" && file_name != ".brel.toml" {
+            command.push_str(" --config ");
+            command.push_str(&tag_template::shell_escape_single(
+                path.to_string_lossy().as_ref(),
+            ));
+        }
     }
-
-    format!(
-        "brel next-version --config {}",
-        tag_template::shell_escape_single(path.to_string_lossy().as_ref())
-    )
+    if let Some(profile) = profile {
+        command.push_str(" --profile ");
+        command.push_str(&tag_template::shell_escape_single(profile));
+    }
+    command
 }
 
-fn print_diff(before: &str, after: &str) {
-    let diff = TextDiff::from_lines(before, after);
-    let unified = diff
+fn unified_diff(before: &str, after: &str) -> String {
+    TextDiff::from_lines(before, after)
         .unified_diff()
         .context_radius(3)
         .header("current", "proposed")
-        .to_string();
+        .to_string()
+}
 
+fn print_diff(before: &str, after: &str) {
+    let unified = unified_diff(before, after);
     if unified.trim().is_empty() {
         println!("No textual diff.");
     } else {
@@ -346,6 +562,7 @@ fn print_diff(before: &str, after: &str) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::{ScriptedRunner, ok, status};
     use std::cell::RefCell;
     use tempfile::tempdir;
 
@@ -375,13 +592,32 @@ mod tests {
                 .clone()
                 .unwrap_or_else(|| configured_branch.to_string()))
         }
+
+        fn confirm_version_updates(
+            &mut self,
+            _config_path: &Path,
+            _proposal: &str,
+        ) -> Result<bool> {
+            Ok(false)
+        }
+    }
+
+    // No `origin` remote and no `gh`, so nothing is detected and the configured branch is used.
+    fn offline_runner() -> ScriptedRunner {
+        let mut runner = ScriptedRunner::new(vec![status(2)]);
+        runner.missing_programs.push("gh");
+        runner
     }
 
     fn init_options(yes: bool, dry_run: bool) -> InitOptions {
         InitOptions {
             config_path: None,
+            profile: None,
             yes,
             dry_run,
+            force: false,
+            format: OutputFormat::Human,
+            working_dir: None,
         }
     }
 
@@ -390,7 +626,13 @@ mod tests {
         let temp_dir = tempdir().unwrap();
         let mut interactor = MockInteractor::default();
 
-        run_with_interactor(temp_dir.path(), &init_options(true, false), &mut interactor).unwrap();
+        run_with_interactor(
+            temp_dir.path(),
+            &init_options(true, false),
+            &mut interactor,
+            &mut offline_runner(),
+        )
+        .unwrap();
 
         let workflow = temp_dir.path().join(".github/workflows/release-pr.yml");
         let content = fs::read_to_string(workflow).unwrap();
@@ -411,6 +653,63 @@ mod tests {
         assert!(!content.contains("pull_request:"));
     }
 
+    #[test]
+    fn default_branch_falls_back_to_gh_through_the_runner() {
+        let temp_dir = tempdir().unwrap();
+        let mut interactor = MockInteractor::default();
+        *interactor.selected_branch.borrow_mut() = Some("trunk".to_string());
+        let mut runner = ScriptedRunner::new(vec![
+            ok("git@github.com:acme/demo.git\n"),
+            ok("gho_token\n"),
+            ok(r#"{"defaultBranchRef":{"name":"trunk"}}"#),
+        ]);
+
+        run_with_interactor(
+            temp_dir.path(),
+            &init_options(false, false),
+            &mut interactor,
+            &mut runner,
+        )
+        .unwrap();
+
+        assert_eq!(runner.programs(), vec!["git", "gh", "gh"]);
+        assert_eq!(runner.calls[0].args, vec!["remote", "get-url", "origin"]);
+        assert_eq!(interactor.branch_select_calls, 1);
+        let content =
+            fs::read_to_string(temp_dir.path().join(".github/workflows/release-pr.yml")).unwrap();
+        assert!(content.contains("- trunk"));
+    }
+
+    #[test]
+    fn gitlab_provider_is_refused_until_release_pr_supports_it() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("brel.toml"),
+            "providers = [\"github\", \"gitlab\"]\n",
+        )
+        .unwrap();
+        let mut interactor = MockInteractor::default();
+
+        let err = run_with_interactor(
+            temp_dir.path(),
+            &init_options(true, false),
+            &mut interactor,
+            &mut offline_runner(),
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains(
+            "Provider `gitlab` is configured, but `brel init` currently supports only `github`"
+        ));
+        assert!(!temp_dir.path().join(".gitlab-ci.yml").exists());
+        assert!(
+            !temp_dir
+                .path()
+                .join(".github/workflows/release-pr.yml")
+                .exists()
+        );
+    }
+
     #[test]
     fn changelog_step_can_be_disabled() {
         let temp_dir = tempdir().unwrap();
@@ -424,13 +723,46 @@ enabled = false
         .unwrap();
         let mut interactor = MockInteractor::default();
 
-        run_with_interactor(temp_dir.path(), &init_options(true, false), &mut interactor).unwrap();
+        run_with_interactor(
+            temp_dir.path(),
+            &init_options(true, false),
+            &mut interactor,
+            &mut offline_runner(),
+        )
+        .unwrap();
 
         let workflow = temp_dir.path().join(".github/workflows/release-pr.yml");
         let content = fs::read_to_string(workflow).unwrap();
         assert!(!content.contains("uses: orhun/git-cliff-action@v4"));
     }
 
+    #[test]
+    fn changelog_config_file_is_passed_to_git_cliff() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("brel.toml"),
+            r#"
+[release_pr.changelog]
+config_file = ".github/cliff.toml"
+"#,
+        )
+        .unwrap();
+        let mut interactor = MockInteractor::default();
+
+        run_with_interactor(
+            temp_dir.path(),
+            &init_options(true, false),
+            &mut interactor,
+            &mut offline_runner(),
+        )
+        .unwrap();
+
+        let workflow = temp_dir.path().join(".github/workflows/release-pr.yml");
+        let content = fs::read_to_string(workflow).unwrap();
+        assert!(content.contains("args: --config .github/cliff.toml --unreleased --tag"));
+        assert!(!content.contains("config: cliff.toml"));
+    }
+
     #[test]
     fn tagging_step_can_be_enabled() {
         let temp_dir = tempdir().unwrap();
@@ -444,7 +776,13 @@ enabled = true
         .unwrap();
         let mut interactor = MockInteractor::default();
 
-        run_with_interactor(temp_dir.path(), &init_options(true, false), &mut interactor).unwrap();
+        run_with_interactor(
+            temp_dir.path(),
+            &init_options(true, false),
+            &mut interactor,
+            &mut offline_runner(),
+        )
+        .unwrap();
 
         let workflow = temp_dir.path().join(".github/workflows/release-pr.yml");
         let content = fs::read_to_string(workflow).unwrap();
@@ -456,6 +794,192 @@ enabled = true
         assert!(content.contains("token: ${{ secrets.BREL_TAG_PUSH_TOKEN }}"));
     }
 
+    #[test]
+    fn signed_tagging_renders_signing_step() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("brel.toml"),
+            r#"
+[release_pr.tagging]
+enabled = true
+sign = true
+"#,
+        )
+        .unwrap();
+        let mut interactor = MockInteractor::default();
+
+        run_with_interactor(
+            temp_dir.path(),
+            &init_options(true, false),
+            &mut interactor,
+            &mut offline_runner(),
+        )
+        .unwrap();
+
+        let workflow = temp_dir.path().join(".github/workflows/release-pr.yml");
+        let content = fs::read_to_string(workflow).unwrap();
+        assert!(content.contains("Import tag signing key"));
+        assert!(content.contains("tag -s \"${tag}\""));
+        assert!(content.contains("-c user.email='brel[bot]@users.noreply.github.com'"));
+    }
+
+    #[test]
+    fn outdated_managed_workflow_is_detected() {
+        let temp_dir = tempdir().unwrap();
+        let workflow = temp_dir.path().join(".github/workflows/release-pr.yml");
+        fs::create_dir_all(workflow.parent().unwrap()).unwrap();
+        let config = config::load(None, temp_dir.path()).unwrap();
+
+        fs::write(&workflow, "# managed-by: brel\nname: old\n").unwrap();
+        assert_eq!(
+            find_outdated_workflow(temp_dir.path(), &config, None).unwrap(),
+            Some(PathBuf::from(".github/workflows/release-pr.yml"))
+        );
+
+        let current = render_release_pr_workflow(&config, Provider::Github, "main", None).unwrap();
+        fs::write(&workflow, current).unwrap();
+        assert_eq!(
+            find_outdated_workflow(temp_dir.path(), &config, None).unwrap(),
+            None
+        );
+
+        fs::write(&workflow, "name: hand-written\n").unwrap();
+        assert_eq!(
+            find_outdated_workflow(temp_dir.path(), &config, None).unwrap(),
+            None
+        );
+
+        // A branch picked at init time differs from `default_branch` but is not drift.
+        let develop =
+            render_release_pr_workflow(&config, Provider::Github, "develop", None).unwrap();
+        fs::write(&workflow, develop).unwrap();
+        assert_eq!(
+            find_outdated_workflow(temp_dir.path(), &config, None).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn workflow_commands_pass_selected_profile() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("brel.toml"),
+            r#"
+[profile.staging]
+default_branch = "staging"
+"#,
+        )
+        .unwrap();
+        let config = config::load_with_profile(None, temp_dir.path(), Some("staging")).unwrap();
+
+        let rendered =
+            render_release_pr_workflow(&config, Provider::Github, "staging", None).unwrap();
+        assert!(rendered.contains("run: brel release-pr --profile staging"));
+        assert!(rendered.contains("brel next-version --profile staging"));
+    }
+
+    #[test]
+    fn custom_managed_marker_is_rendered_and_detected() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("brel.toml"),
+            r#"
+[release_pr]
+managed_marker = "managed-by: acme-release"
+
+[release_pr.tagging]
+enabled = true
+"#,
+        )
+        .unwrap();
+        let workflow = temp_dir.path().join(".github/workflows/release-pr.yml");
+        fs::create_dir_all(workflow.parent().unwrap()).unwrap();
+        let config = config::load(None, temp_dir.path()).unwrap();
+
+        let rendered = render_release_pr_workflow(&config, Provider::Github, "main", None).unwrap();
+        assert!(rendered.starts_with("# managed-by: acme-release\n"));
+        assert!(rendered.contains("grep -Eq '<!-- managed-by: acme-release( base=[^ ]+)? -->'"));
+        assert!(workflow::is_managed(
+            &rendered,
+            &config.release_pr.managed_marker
+        ));
+
+        fs::write(&workflow, "# managed-by: acme-release\nname: old\n").unwrap();
+        assert_eq!(
+            find_outdated_workflow(temp_dir.path(), &config, None).unwrap(),
+            Some(PathBuf::from(".github/workflows/release-pr.yml"))
+        );
+        fs::write(&workflow, "# managed-by: brel\nname: old\n").unwrap();
+        assert_eq!(
+            find_outdated_workflow(temp_dir.path(), &config, None).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn tag_message_template_is_rendered_into_workflow() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("brel.toml"),
+            r#"
+[release_pr.tagging]
+enabled = true
+annotated = true
+message_template = "brel release {version} ({date})"
+"#,
+        )
+        .unwrap();
+        let mut interactor = MockInteractor::default();
+
+        run_with_interactor(
+            temp_dir.path(),
+            &init_options(true, false),
+            &mut interactor,
+            &mut offline_runner(),
+        )
+        .unwrap();
+
+        let workflow = temp_dir.path().join(".github/workflows/release-pr.yml");
+        let content = fs::read_to_string(workflow).unwrap();
+        assert!(
+            content.contains(
+                "tag -a \"${tag}\" -m \"brel release ${version} ($(date -u +%Y-%m-%d))\""
+            )
+        );
+    }
+
+    #[test]
+    fn build_metadata_template_is_appended_to_workflow_tag() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("brel.toml"),
+            r#"
+[release_pr]
+build_metadata_template = "sha.{short_sha}"
+
+[release_pr.tagging]
+enabled = true
+"#,
+        )
+        .unwrap();
+        let mut interactor = MockInteractor::default();
+
+        run_with_interactor(
+            temp_dir.path(),
+            &init_options(true, false),
+            &mut interactor,
+            &mut offline_runner(),
+        )
+        .unwrap();
+
+        let workflow = temp_dir.path().join(".github/workflows/release-pr.yml");
+        let content = fs::read_to_string(workflow).unwrap();
+        assert!(
+            content
+                .contains("tag=\"${prefix}${version}+\"sha.${merge_commit_sha:0:7}\"${suffix}\"")
+        );
+    }
+
     #[test]
     fn tagging_template_updates_workflow_tag_generation() {
         let temp_dir = tempdir().unwrap();
@@ -470,7 +994,13 @@ tag_template = "release-{version}-prod"
         .unwrap();
         let mut interactor = MockInteractor::default();
 
-        run_with_interactor(temp_dir.path(), &init_options(true, false), &mut interactor).unwrap();
+        run_with_interactor(
+            temp_dir.path(),
+            &init_options(true, false),
+            &mut interactor,
+            &mut offline_runner(),
+        )
+        .unwrap();
 
         let workflow = temp_dir.path().join(".github/workflows/release-pr.yml");
         let content = fs::read_to_string(workflow).unwrap();
@@ -483,6 +1013,40 @@ tag_template = "release-{version}-prod"
         assert!(content.contains("suffix=-prod"));
     }
 
+    #[test]
+    fn changelog_tag_template_only_changes_the_git_cliff_tag() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("brel.toml"),
+            r#"
+[release_pr.changelog]
+tag_template = "release-{version}"
+
+[release_pr.tagging]
+enabled = true
+tag_template = "v{version}"
+"#,
+        )
+        .unwrap();
+        let mut interactor = MockInteractor::default();
+
+        run_with_interactor(
+            temp_dir.path(),
+            &init_options(true, false),
+            &mut interactor,
+            &mut offline_runner(),
+        )
+        .unwrap();
+
+        let workflow = temp_dir.path().join(".github/workflows/release-pr.yml");
+        let content = fs::read_to_string(workflow).unwrap();
+        assert!(content.contains(
+            "args: --unreleased --tag release-${{ steps.next-version.outputs.version }}"
+        ));
+        assert!(content.contains("prefix=v"));
+        assert!(!content.contains("prefix=release-"));
+    }
+
     #[test]
     fn managed_file_decline_keeps_existing_content() {
         let temp_dir = tempdir().unwrap();
@@ -499,6 +1063,7 @@ tag_template = "release-{version}-prod"
             temp_dir.path(),
             &init_options(false, false),
             &mut interactor,
+            &mut offline_runner(),
         )
         .unwrap();
 
@@ -523,6 +1088,7 @@ tag_template = "release-{version}-prod"
             temp_dir.path(),
             &init_options(false, false),
             &mut interactor,
+            &mut offline_runner(),
         )
         .unwrap();
 
@@ -539,7 +1105,13 @@ tag_template = "release-{version}-prod"
         fs::write(&workflow, "# managed-by: brel\nname: old\n").unwrap();
 
         let mut interactor = MockInteractor::default();
-        run_with_interactor(temp_dir.path(), &init_options(true, false), &mut interactor).unwrap();
+        run_with_interactor(
+            temp_dir.path(),
+            &init_options(true, false),
+            &mut interactor,
+            &mut offline_runner(),
+        )
+        .unwrap();
         assert_eq!(interactor.overwrite_calls, 0);
     }
 
@@ -551,14 +1123,63 @@ tag_template = "release-{version}-prod"
         fs::write(&workflow, "name: user workflow\n").unwrap();
 
         let mut interactor = MockInteractor::default();
-        let err = run_with_interactor(temp_dir.path(), &init_options(true, false), &mut interactor)
-            .unwrap_err();
+        let err = run_with_interactor(
+            temp_dir.path(),
+            &init_options(true, false),
+            &mut interactor,
+            &mut offline_runner(),
+        )
+        .unwrap_err();
         assert!(
             err.to_string()
                 .contains("Refusing to overwrite unmanaged workflow")
         );
     }
 
+    #[test]
+    fn force_with_yes_replaces_unmanaged_workflow() {
+        let temp_dir = tempdir().unwrap();
+        let workflow = temp_dir.path().join(".github/workflows/release-pr.yml");
+        fs::create_dir_all(workflow.parent().unwrap()).unwrap();
+        fs::write(&workflow, "name: user workflow\n").unwrap();
+
+        let mut interactor = MockInteractor::default();
+        let err = run_with_interactor(
+            temp_dir.path(),
+            &InitOptions {
+                force: true,
+                format: OutputFormat::Human,
+                ..init_options(false, false)
+            },
+            &mut interactor,
+            &mut offline_runner(),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Refusing to overwrite unmanaged workflow")
+        );
+        assert_eq!(
+            fs::read_to_string(&workflow).unwrap(),
+            "name: user workflow\n"
+        );
+
+        run_with_interactor(
+            temp_dir.path(),
+            &InitOptions {
+                force: true,
+                format: OutputFormat::Human,
+                ..init_options(true, false)
+            },
+            &mut interactor,
+            &mut offline_runner(),
+        )
+        .unwrap();
+        let content = fs::read_to_string(&workflow).unwrap();
+        assert!(content.starts_with("# managed-by: brel\n"));
+        assert_eq!(interactor.overwrite_calls, 0);
+    }
+
     #[test]
     fn dry_run_does_not_mutate_existing_file() {
         let temp_dir = tempdir().unwrap();
@@ -567,7 +1188,13 @@ tag_template = "release-{version}-prod"
         fs::write(&workflow, "# managed-by: brel\nname: old\n").unwrap();
 
         let mut interactor = MockInteractor::default();
-        run_with_interactor(temp_dir.path(), &init_options(true, true), &mut interactor).unwrap();
+        run_with_interactor(
+            temp_dir.path(),
+            &init_options(true, true),
+            &mut interactor,
+            &mut offline_runner(),
+        )
+        .unwrap();
 
         let content = fs::read_to_string(workflow).unwrap();
         assert_eq!(content, "# managed-by: brel\nname: old\n");
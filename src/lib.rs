@@ -0,0 +1,46 @@
+//! Library interface for brel, the release PR and versioning tool.
+//!
+//! The `brel` binary is a thin wrapper around this crate. Embedders can load a config, compute the
+//! next release version, and apply version updates without shelling out:
+//!
+//! ```no_run
+//! use std::path::Path;
+//!
+//! let repo_root = Path::new(".");
+//! let config = brel::load_config(None, repo_root)?;
+//! if let Some(next) = brel::compute_next_version(repo_root, &config)? {
+//!     println!("next release: {} ({})", next.version, next.tag);
+//!     brel::apply_version_updates(
+//!         repo_root,
+//!         brel::VersionValues::from_config(&next.version.to_string(), &next.tag, &config.release_pr),
+//!         &config.release_pr.version_updates,
+//!         &config.release_pr.format_overrides,
+//!         brel::SelectorMatching::from_config(&config.release_pr),
+//!     )?;
+//! }
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+pub mod cli;
+pub mod config;
+pub mod config_query;
+pub mod detect;
+pub mod error;
+mod github_api;
+pub mod init;
+pub mod logging;
+mod path_glob;
+pub mod release_pr;
+pub mod status;
+mod tag_template;
+mod template;
+#[cfg(test)]
+mod test_support;
+mod version_selector;
+pub mod version_update;
+mod workflow;
+
+pub use config::{ResolvedConfig, load as load_config};
+pub use error::BrelError;
+pub use release_pr::{NextReleaseInfo, compute_next_version};
+pub use version_update::{SelectorMatching, UpdateReport, VersionValues, apply_version_updates};
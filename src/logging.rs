@@ -0,0 +1,51 @@
+use std::fmt;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static VERBOSE: AtomicBool = AtomicBool::new(false);
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+pub fn set_verbose(enabled: bool) {
+    VERBOSE.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_verbose() -> bool {
+    VERBOSE.load(Ordering::Relaxed)
+}
+
+pub fn set_quiet(enabled: bool) {
+    QUIET.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+pub fn emit(message: fmt::Arguments<'_>) {
+    if std::io::stderr().is_terminal() {
+        eprintln!("\x1b[2mverbose:\x1b[0m {message}");
+    } else {
+        eprintln!("verbose: {message}");
+    }
+}
+
+/// Prints a step to stderr when `--verbose` is set; a no-op otherwise.
+macro_rules! verbose {
+    ($($arg:tt)*) => {
+        if $crate::logging::is_verbose() {
+            $crate::logging::emit(format_args!($($arg)*));
+        }
+    };
+}
+
+/// Prints a status line to stdout unless `--quiet` is set. Warnings and command output such as
+/// `next-version`'s version keep using `eprintln!`/`println!` directly.
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if !$crate::logging::is_quiet() {
+            println!($($arg)*);
+        }
+    };
+}
+
+pub(crate) use {info, verbose};
@@ -1,16 +1,7 @@
-mod cli;
-mod config;
-mod init;
-mod release_pr;
-mod tag_template;
-mod template;
-mod version_selector;
-mod version_update;
-mod workflow;
-
 use anyhow::Result;
+use brel::cli::{Cli, Commands};
+use brel::{config_query, detect, init, release_pr, status};
 use clap::Parser;
-use cli::{Cli, Commands};
 
 fn main() {
     if let Err(err) = run() {
@@ -21,9 +12,15 @@ fn main() {
 
 fn run() -> Result<()> {
     let cli = Cli::parse();
+    brel::logging::set_verbose(cli.verbose);
+    brel::logging::set_quiet(cli.quiet);
     match cli.command {
         Commands::Init(args) => init::run(args),
         Commands::ReleasePr(args) => release_pr::run(args),
         Commands::NextVersion(args) => release_pr::run_next_version(args),
+        Commands::Config(args) => config_query::run(args),
+        Commands::Detect(args) => detect::run(args),
+        Commands::Tag(args) => release_pr::run_tag(args),
+        Commands::Status(args) => status::run(args),
     }
 }
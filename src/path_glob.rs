@@ -0,0 +1,56 @@
+pub fn is_glob_pattern(value: &str) -> bool {
+    value.contains(['*', '?'])
+}
+
+pub fn matches(pattern: &str, path: &str) -> bool {
+    let candidate = if pattern.contains('/') {
+        path
+    } else {
+        path.rsplit('/').next().unwrap_or(path)
+    };
+    matches_segment(pattern.as_bytes(), candidate.as_bytes())
+}
+
+fn matches_segment(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => (0..=text.len())
+            .any(|skip| !text[..skip].contains(&b'/') && matches_segment(rest, &text[skip..])),
+        Some((b'?', rest)) => text
+            .split_first()
+            .is_some_and(|(ch, remaining)| *ch != b'/' && matches_segment(rest, remaining)),
+        Some((expected, rest)) => text
+            .split_first()
+            .is_some_and(|(ch, remaining)| ch == expected && matches_segment(rest, remaining)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_glob_patterns() {
+        assert!(is_glob_pattern("*.lock"));
+        assert!(is_glob_pattern("packages/?/package.json"));
+        assert!(!is_glob_pattern("Cargo.lock"));
+    }
+
+    #[test]
+    fn patterns_without_slash_match_file_names_anywhere() {
+        assert!(matches("*.lock", "Cargo.lock"));
+        assert!(matches("*.lock", "crates/core/Cargo.lock"));
+        assert!(!matches("*.lock", "Cargo.toml"));
+    }
+
+    #[test]
+    fn patterns_with_slash_match_full_paths_segment_by_segment() {
+        assert!(matches("crates/*/Cargo.toml", "crates/core/Cargo.toml"));
+        assert!(!matches(
+            "crates/*/Cargo.toml",
+            "crates/core/nested/Cargo.toml"
+        ));
+        assert!(matches("v?.json", "v1.json"));
+        assert!(!matches("v?.json", "v12.json"));
+    }
+}
@@ -0,0 +1,109 @@
+use crate::cli::StatusArgs;
+use crate::config::ResolvedConfig;
+use crate::release_pr::{self, CommandRunner, ProcessRunner, ReleasePrStatus, ReleaseStatus};
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Default)]
+pub struct StatusOptions {
+    pub config_path: Option<PathBuf>,
+    pub profile: Option<String>,
+    /// Where config discovery starts; defaults to the repository root.
+    pub working_dir: Option<PathBuf>,
+}
+
+pub fn run(args: StatusArgs) -> Result<()> {
+    let (working_dir, repo_root) = release_pr::current_dirs()?;
+    let options = StatusOptions {
+        config_path: args.config,
+        profile: args.profile,
+        working_dir: Some(working_dir),
+    };
+
+    let mut runner = ProcessRunner;
+    run_with_runner(&repo_root, &options, &mut runner, None)
+}
+
+pub(crate) fn run_with_runner(
+    repo_root: &Path,
+    options: &StatusOptions,
+    runner: &mut dyn CommandRunner,
+    gh_token_override: Option<&str>,
+) -> Result<()> {
+    let config = release_pr::load_supported_config(
+        options.config_path.as_deref(),
+        options.profile.as_deref(),
+        options.working_dir.as_deref(),
+        repo_root,
+        "status",
+        runner,
+    )?;
+    let repo_root = config.source.root_dir(repo_root);
+    release_pr::ensure_program_on_path(runner, "git")?;
+
+    if config.release_pr.groups.is_empty() {
+        return print_status(runner, repo_root, &config, gh_token_override);
+    }
+    for name in config.release_pr.groups.keys() {
+        let group_config = config
+            .for_group(name)
+            .expect("group names come from the config itself");
+        println!("Release group `{name}`:");
+        print_status(runner, repo_root, &group_config, gh_token_override)
+            .with_context(|| format!("Release group `{name}` failed."))?;
+    }
+    Ok(())
+}
+
+fn print_status(
+    runner: &mut dyn CommandRunner,
+    repo_root: &Path,
+    config: &ResolvedConfig,
+    gh_token_override: Option<&str>,
+) -> Result<()> {
+    let status = release_pr::collect_release_status(runner, repo_root, config, gh_token_override)?;
+    for line in format_status(&status) {
+        println!("{line}");
+    }
+    Ok(())
+}
+
+fn format_status(status: &ReleaseStatus) -> Vec<String> {
+    let mut lines = vec![
+        format!(
+            "Latest release: {}",
+            status.latest_tag.as_deref().unwrap_or("none")
+        ),
+        format!("Releasable commits: {}", status.releasable_commits),
+    ];
+    lines.push(match &status.next {
+        Some(next) => match next.bump {
+            Some(bump) => format!(
+                "Next version: {} ({} bump, tag {})",
+                next.version, bump, next.tag
+            ),
+            None => format!("Next version: {} (tag {})", next.version, next.tag),
+        },
+        None => "Next version: none (nothing to release)".to_string(),
+    });
+    lines.push(match &status.release_pr {
+        ReleasePrStatus::Open { number, branch } => {
+            format!("Release PR: #{number} open from `{branch}`")
+        }
+        ReleasePrStatus::NotOpen => "Release PR: none open".to_string(),
+        ReleasePrStatus::Unknown(reason) => format!("Release PR: unknown ({reason})"),
+    });
+    match status.changed_files.as_deref() {
+        None => {}
+        Some([]) => lines.push("Version files: already up to date".to_string()),
+        Some(files) => {
+            lines.push("Version files:".to_string());
+            lines.extend(
+                files
+                    .iter()
+                    .map(|path| format!("  would update `{}`", path.display())),
+            );
+        }
+    }
+    lines
+}
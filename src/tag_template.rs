@@ -1,9 +1,12 @@
 use anyhow::{Result, bail};
-use semver::Version;
+use semver::{BuildMetadata, Version};
 
 pub const DEFAULT_TAG_TEMPLATE: &str = "v{version}";
 pub const VERSION_TOKEN: &str = "{version}";
 const LEGACY_VERSION_TOKEN: &str = "{{version}}";
+pub const DEFAULT_TAG_MESSAGE_TEMPLATE: &str = "Release {tag}";
+const TAG_MESSAGE_TOKENS: [&str; 3] = ["version", "tag", "date"];
+const BUILD_METADATA_TOKENS: [&str; 2] = ["short_sha", "date"];
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TagTemplate {
@@ -40,6 +43,11 @@ impl TagTemplate {
     }
 
     pub fn parse_stable_version(&self, raw_tag: &str) -> Option<Version> {
+        self.parse_version(raw_tag)
+            .filter(|version| version.pre.is_empty())
+    }
+
+    pub fn parse_version(&self, raw_tag: &str) -> Option<Version> {
         let tag = raw_tag.trim();
         if !tag.starts_with(&self.prefix) || !tag.ends_with(&self.suffix) {
             return None;
@@ -49,10 +57,10 @@ impl TagTemplate {
         }
 
         let version_segment = &tag[self.prefix.len()..tag.len() - self.suffix.len()];
-        let version = Version::parse(version_segment).ok()?;
-        if !version.pre.is_empty() || !version.build.is_empty() {
-            return None;
-        }
+        let mut version = Version::parse(version_segment).ok()?;
+        // Build metadata (`+sha.abc1234`) never affects precedence, so the tag counts as its
+        // underlying version.
+        version.build = BuildMetadata::EMPTY;
         Some(version)
     }
 }
@@ -72,9 +80,107 @@ pub fn normalize_tag_template(value: &str) -> Result<String> {
         );
     }
 
+    // A digit or `.` next to the version would merge with it, so a rendered tag like
+    // `v1.2.30` could not be told apart from another version and baselines would never match.
+    let (prefix, suffix) = canonical
+        .split_once(VERSION_TOKEN)
+        .expect("token count was checked above");
+    if prefix.ends_with(|ch: char| ch.is_ascii_digit() || ch == '.') {
+        bail!(
+            "Tag template `{canonical}` is ambiguous: the text before `{VERSION_TOKEN}` must not \
+             end with a digit or `.`."
+        );
+    }
+    if suffix.starts_with(|ch: char| ch.is_ascii_digit() || ch == '.') {
+        bail!(
+            "Tag template `{canonical}` is ambiguous: the text after `{VERSION_TOKEN}` must not \
+             start with a digit or `.`."
+        );
+    }
+
+    Ok(canonical)
+}
+
+pub fn normalize_tag_message_template(value: &str) -> Result<String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        bail!("Tag message template cannot be empty.");
+    }
+
+    let mut canonical = trimmed.to_string();
+    for token in TAG_MESSAGE_TOKENS {
+        canonical = canonical.replace(&format!("{{{{{token}}}}}"), &format!("{{{token}}}"));
+    }
+
+    let rendered = render_tag_message(&canonical, "0.0.0", "v0.0.0", "1970-01-01");
+    if rendered.contains('{') || rendered.contains('}') {
+        bail!(
+            "Tag message template supports only `{{version}}`, `{{tag}}`, and `{{date}}` tokens."
+        );
+    }
+    if rendered.contains('\n') {
+        bail!("Tag message template must be a single line.");
+    }
+
     Ok(canonical)
 }
 
+pub fn render_tag_message(template: &str, version: &str, tag: &str, date: &str) -> String {
+    template
+        .replace("{version}", version)
+        .replace("{tag}", tag)
+        .replace("{date}", date)
+}
+
+pub fn normalize_build_metadata_template(value: &str) -> Result<String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        bail!("Build metadata template cannot be empty.");
+    }
+
+    let mut canonical = trimmed.to_string();
+    for token in BUILD_METADATA_TOKENS {
+        canonical = canonical.replace(&format!("{{{{{token}}}}}"), &format!("{{{token}}}"));
+    }
+
+    let rendered = render_build_metadata(&canonical, "abc1234", "1970-01-01");
+    if BuildMetadata::new(&rendered).is_err() {
+        bail!(
+            "Build metadata template must render dot-separated ASCII letters, digits, and `-` \
+             (tokens: `{{short_sha}}`, `{{date}}`), for example `sha.{{short_sha}}`."
+        );
+    }
+
+    Ok(canonical)
+}
+
+pub fn render_build_metadata(template: &str, short_sha: &str, date: &str) -> String {
+    template
+        .replace("{short_sha}", short_sha)
+        .replace("{date}", date)
+}
+
+pub fn build_metadata_shell_expr(template: &str) -> String {
+    // Normalized templates contain no shell metacharacters besides the substituted tokens.
+    // `{date}` is the tagged commit's date, like `brel tag`, so a rerun renders the same tag.
+    let expr = render_build_metadata(
+        template,
+        "${merge_commit_sha:0:7}",
+        "$(git log -1 --format=%cs \"${merge_commit_sha}\")",
+    );
+    format!("\"{expr}\"")
+}
+
+pub fn tag_message_shell_expr(template: &str) -> String {
+    let escaped = template
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('$', "\\$")
+        .replace('`', "\\`");
+    let expr = render_tag_message(&escaped, "${version}", "${tag}", "$(date -u +%Y-%m-%d)");
+    format!("\"{expr}\"")
+}
+
 pub fn shell_escape_single(value: &str) -> String {
     if value.is_empty() {
         return "''".to_string();
@@ -109,6 +215,38 @@ mod tests {
         assert!(normalize_tag_template("{version}-{version}").is_err());
     }
 
+    #[test]
+    fn rejects_templates_that_do_not_round_trip() {
+        for template in [
+            "v{version}0",
+            "v{version}.final",
+            "release1{version}",
+            "v.{version}",
+        ] {
+            let err = TagTemplate::parse(template).unwrap_err();
+            assert!(
+                err.to_string().contains("is ambiguous"),
+                "{template}: {err}"
+            );
+        }
+
+        for template in [
+            "v{version}",
+            "release-{version}",
+            "{version}-linux",
+            "app@{version}",
+        ] {
+            let parsed = TagTemplate::parse(template).unwrap();
+            for version in ["0.1.0", "1.2.3", "10.20.30"] {
+                assert_eq!(
+                    parsed.parse_stable_version(&parsed.render(version)),
+                    Some(Version::parse(version).unwrap()),
+                    "{template}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn renders_and_parses_stable_versions() {
         let template = TagTemplate::parse("release-{version}").unwrap();
@@ -124,6 +262,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parses_prerelease_versions_when_requested() {
+        let template = TagTemplate::parse("v{version}").unwrap();
+        assert_eq!(
+            template.parse_version("v1.5.0-rc.1"),
+            Some(Version::parse("1.5.0-rc.1").unwrap())
+        );
+        assert_eq!(
+            template.parse_version("v1.2.3"),
+            Some(Version::new(1, 2, 3))
+        );
+        assert_eq!(
+            template.parse_version("v1.2.3+build.5"),
+            Some(Version::new(1, 2, 3))
+        );
+    }
+
+    #[test]
+    fn tags_with_build_metadata_parse_as_their_base_version() {
+        let template = TagTemplate::parse("v{version}").unwrap();
+        assert_eq!(
+            template.parse_stable_version("v1.2.3+sha.abc1234"),
+            Some(Version::new(1, 2, 3))
+        );
+        assert!(
+            template
+                .parse_stable_version("v1.3.0-rc.1+sha.abc1234")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn validates_and_renders_build_metadata_templates() {
+        assert_eq!(
+            normalize_build_metadata_template("sha.{{short_sha}}").unwrap(),
+            "sha.{short_sha}"
+        );
+        assert!(normalize_build_metadata_template("sha {short_sha}").is_err());
+        assert!(normalize_build_metadata_template("{commit}").is_err());
+        assert_eq!(
+            render_build_metadata("sha.{short_sha}.{date}", "abc1234", "2024-01-01"),
+            "sha.abc1234.2024-01-01"
+        );
+        assert_eq!(
+            build_metadata_shell_expr("sha.{short_sha}"),
+            "\"sha.${merge_commit_sha:0:7}\""
+        );
+        assert_eq!(
+            build_metadata_shell_expr("{date}"),
+            "\"$(git log -1 --format=%cs \"${merge_commit_sha}\")\""
+        );
+    }
+
+    #[test]
+    fn validates_and_renders_tag_message_templates() {
+        assert_eq!(
+            normalize_tag_message_template("Release {{tag}} ({date})").unwrap(),
+            "Release {tag} ({date})"
+        );
+        assert!(normalize_tag_message_template("Release {name}").is_err());
+        assert!(normalize_tag_message_template(" ").is_err());
+        assert_eq!(
+            render_tag_message("{tag}: version {version}", "1.2.3", "v1.2.3", "2024-01-01"),
+            "v1.2.3: version 1.2.3"
+        );
+    }
+
+    #[test]
+    fn tag_message_shell_expr_substitutes_runtime_values() {
+        assert_eq!(
+            tag_message_shell_expr("Release {tag} on {date}"),
+            "\"Release ${tag} on $(date -u +%Y-%m-%d)\""
+        );
+        assert_eq!(
+            tag_message_shell_expr("Cost $5 \"{version}\""),
+            "\"Cost \\$5 \\\"${version}\\\"\""
+        );
+    }
+
     #[test]
     fn shell_escape_wraps_non_safe_values() {
         assert_eq!(shell_escape_single(""), "''");
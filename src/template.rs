@@ -2,6 +2,7 @@ use crate::config::Provider;
 use anyhow::{Context, Result, bail};
 use handlebars::{Handlebars, no_escape};
 use serde::Serialize;
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WorkflowTemplate {
@@ -10,6 +11,7 @@ pub enum WorkflowTemplate {
 
 #[derive(Debug, Serialize)]
 pub struct WorkflowRenderContext<'a> {
+    pub managed_marker: &'a str,
     pub default_branch: &'a str,
     pub release_pr_command: &'a str,
     pub next_version_command: &'a str,
@@ -18,11 +20,23 @@ pub struct WorkflowRenderContext<'a> {
     pub next_version_non_empty_expr: &'a str,
     pub next_version_output_expr: &'a str,
     pub next_version_tag_output_expr: &'a str,
+    pub next_version_bump_output_expr: &'a str,
+    pub previous_tag_command: Option<&'a str>,
     pub changelog_enabled: bool,
+    pub changelog_range_arg: &'a str,
+    pub changelog_config_file: Option<&'a str>,
     pub changelog_output_file: &'a str,
     pub tagging_enabled: bool,
     pub tagging_template_prefix_shell: &'a str,
     pub tagging_template_suffix_shell: &'a str,
+    pub tagging_annotated: bool,
+    pub tagging_sign: bool,
+    pub tagger_name_shell: &'a str,
+    pub tagger_email_shell: &'a str,
+    pub tagging_message_shell: &'a str,
+    pub tagging_build_metadata_shell: Option<&'a str>,
+    pub tagging_gpg_key_expr: &'a str,
+    pub tagging_gpg_passphrase_expr: &'a str,
 }
 
 #[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
@@ -31,33 +45,84 @@ pub struct ReleasePrCommitContext<'a> {
     pub subject: &'a str,
 }
 
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub struct ReleasePrContributorContext<'a> {
+    pub name: &'a str,
+    pub email: &'a str,
+}
+
 #[derive(Debug, Serialize)]
 pub struct ReleasePrBodyContext<'a> {
     pub version: &'a str,
     pub tag: &'a str,
     pub base_branch: &'a str,
     pub release_branch: &'a str,
+    pub managed_marker: &'a str,
+    pub compare_url: Option<&'a str>,
     pub commits: &'a [ReleasePrCommitContext<'a>],
+    pub commit_count: usize,
+    /// Included commits left out of `commits` by `release_pr.max_body_commits`.
+    pub more_commits: usize,
+    pub contributors: &'a [ReleasePrContributorContext<'a>],
+    pub changelog_section: &'a str,
+    pub counts: &'a BTreeMap<String, usize>,
+    pub closes_issues: &'a [String],
+    /// Issue references without a closing keyword; listed, but never closed by the merge.
+    pub referenced_issues: &'a [String],
 }
 
-pub const MANAGED_RELEASE_PR_MARKER: &str = "<!-- managed-by: brel -->";
-
 const GITHUB_RELEASE_PR_TEMPLATE: &str =
     include_str!("../templates/workflows/github/release-pr.yml.hbs");
-const DEFAULT_RELEASE_PR_BODY_TEMPLATE: &str = r#"<!-- managed-by: brel -->
+const DEFAULT_RELEASE_PR_TITLE_TEMPLATE: &str = "Release {{tag}}";
+const DEFAULT_RELEASE_PR_BODY_TEMPLATE: &str = r#"{{managed_marker}}
 ## Release {{tag}}
 
 Base branch: `{{base_branch}}`
 Release branch: `{{release_branch}}`
+{{#if compare_url}}
+Full diff: {{compare_url}}
+{{/if}}
 
 ### Included commits
 {{#if commits}}
+{{commit_count}} {{#if (eq commit_count 1)}}commit{{else}}commits{{/if}} from {{len contributors}} {{#if (eq (len contributors) 1)}}contributor{{else}}contributors{{/if}}.
+
 {{#each commits}}
 - {{subject}} ({{sha_short}})
 {{/each}}
+{{#if more_commits}}
+- ...and {{more_commits}} more {{#if (eq more_commits 1)}}commit{{else}}commits{{/if}}
+{{/if}}
 {{else}}
 - No commit summaries available.
 {{/if}}
+{{#if closes_issues}}
+
+### Closes
+{{#each closes_issues}}
+- Closes {{this}}
+{{/each}}
+{{/if}}
+{{#if referenced_issues}}
+
+### References
+{{#each referenced_issues}}
+- {{this}}
+{{/each}}
+{{/if}}
+{{#if changelog_section}}
+
+### Changelog
+
+{{changelog_section}}
+{{/if}}
+{{#if contributors}}
+
+### Contributors
+{{#each contributors}}
+- {{name}}
+{{/each}}
+{{/if}}
 "#;
 
 pub fn render_workflow(
@@ -76,6 +141,24 @@ pub fn render_workflow(
     }
 }
 
+pub fn managed_release_pr_marker(marker: &str, base_branch: &str) -> String {
+    format!("<!-- {marker} base={base_branch} -->")
+}
+
+pub fn legacy_release_pr_marker(marker: &str) -> String {
+    format!("<!-- {marker} -->")
+}
+
+pub fn is_managed_release_pr_body(body: &str, marker: &str, base_branch: &str) -> bool {
+    if body.contains(&managed_release_pr_marker(marker, base_branch)) {
+        return true;
+    }
+    if body.contains(&format!("<!-- {marker} base=")) {
+        return false;
+    }
+    body.contains(&legacy_release_pr_marker(marker))
+}
+
 pub fn render_release_pr_body(
     context: &ReleasePrBodyContext<'_>,
     template_override: Option<&str>,
@@ -84,6 +167,22 @@ pub fn render_release_pr_body(
     render_template("release-pr-body", template, context)
 }
 
+pub fn render_release_pr_title(
+    context: &ReleasePrBodyContext<'_>,
+    template_override: Option<&str>,
+) -> Result<String> {
+    let template = template_override.unwrap_or(DEFAULT_RELEASE_PR_TITLE_TEMPLATE);
+    let rendered = render_template("release-pr-title", template, context)?;
+    let title = rendered.trim();
+    if title.is_empty() {
+        bail!("Release PR title template rendered an empty title.");
+    }
+    if title.contains('\n') {
+        bail!("Release PR title template must render a single line.");
+    }
+    Ok(title.to_string())
+}
+
 fn render_template<T: Serialize>(name: &str, template_source: &str, context: &T) -> Result<String> {
     let mut handlebars = Handlebars::new();
     handlebars.register_escape_fn(no_escape);
@@ -106,6 +205,7 @@ mod tests {
             Provider::Github,
             WorkflowTemplate::ReleasePr,
             &WorkflowRenderContext {
+                managed_marker: "managed-by: brel",
                 default_branch: "main",
                 release_pr_command: "brel release-pr --config custom.toml",
                 next_version_command: "brel next-version --config custom.toml",
@@ -114,11 +214,23 @@ mod tests {
                 next_version_non_empty_expr: "${{ steps.next-version.outputs.version != '' }}",
                 next_version_output_expr: "${{ steps.next-version.outputs.version }}",
                 next_version_tag_output_expr: "v${{ steps.next-version.outputs.version }}",
+                next_version_bump_output_expr: "${{ steps.next-version.outputs.bump }}",
+                previous_tag_command: None,
                 changelog_enabled: true,
+                changelog_range_arg: "--unreleased",
+                changelog_config_file: None,
                 changelog_output_file: "CHANGELOG.md",
                 tagging_enabled: false,
                 tagging_template_prefix_shell: "'v'",
                 tagging_template_suffix_shell: "''",
+                tagging_annotated: false,
+                tagging_sign: false,
+                tagger_name_shell: "'brel[bot]'",
+                tagger_email_shell: "'brel[bot]@users.noreply.github.com'",
+                tagging_message_shell: "\"Release ${tag}\"",
+                tagging_build_metadata_shell: None,
+                tagging_gpg_key_expr: "${{ secrets.BREL_TAG_GPG_PRIVATE_KEY }}",
+                tagging_gpg_passphrase_expr: "${{ secrets.BREL_TAG_GPG_PASSPHRASE }}",
             },
         )
         .unwrap();
@@ -127,6 +239,7 @@ mod tests {
         assert!(rendered.contains("- main"));
         assert!(rendered.contains("run: brel release-pr --config custom.toml"));
         assert!(rendered.contains("id: next-version"));
+        assert!(rendered.contains("bump: ${{ steps.next-version.outputs.bump }}"));
         assert!(rendered.contains("next_version=\"$(brel next-version --config custom.toml)\""));
         assert!(rendered.contains("GH_TOKEN: ${{ github.token }}"));
         assert!(rendered.contains("if: ${{ steps.next-version.outputs.version != '' }}"));
@@ -147,6 +260,7 @@ mod tests {
             Provider::Github,
             WorkflowTemplate::ReleasePr,
             &WorkflowRenderContext {
+                managed_marker: "managed-by: brel",
                 default_branch: "main",
                 release_pr_command: "brel release-pr",
                 next_version_command: "brel next-version",
@@ -155,11 +269,23 @@ mod tests {
                 next_version_non_empty_expr: "${{ steps.next-version.outputs.version != '' }}",
                 next_version_output_expr: "${{ steps.next-version.outputs.version }}",
                 next_version_tag_output_expr: "v${{ steps.next-version.outputs.version }}",
+                next_version_bump_output_expr: "${{ steps.next-version.outputs.bump }}",
+                previous_tag_command: None,
                 changelog_enabled: false,
+                changelog_range_arg: "--unreleased",
+                changelog_config_file: None,
                 changelog_output_file: "CHANGELOG.md",
                 tagging_enabled: false,
                 tagging_template_prefix_shell: "'v'",
                 tagging_template_suffix_shell: "''",
+                tagging_annotated: false,
+                tagging_sign: false,
+                tagger_name_shell: "'brel[bot]'",
+                tagger_email_shell: "'brel[bot]@users.noreply.github.com'",
+                tagging_message_shell: "\"Release ${tag}\"",
+                tagging_build_metadata_shell: None,
+                tagging_gpg_key_expr: "${{ secrets.BREL_TAG_GPG_PRIVATE_KEY }}",
+                tagging_gpg_passphrase_expr: "${{ secrets.BREL_TAG_GPG_PASSPHRASE }}",
             },
         )
         .unwrap();
@@ -173,6 +299,7 @@ mod tests {
             Provider::Github,
             WorkflowTemplate::ReleasePr,
             &WorkflowRenderContext {
+                managed_marker: "managed-by: brel",
                 default_branch: "main",
                 release_pr_command: "brel release-pr",
                 next_version_command: "brel next-version",
@@ -181,11 +308,23 @@ mod tests {
                 next_version_non_empty_expr: "${{ steps.next-version.outputs.version != '' }}",
                 next_version_output_expr: "${{ steps.next-version.outputs.version }}",
                 next_version_tag_output_expr: "v${{ steps.next-version.outputs.version }}",
+                next_version_bump_output_expr: "${{ steps.next-version.outputs.bump }}",
+                previous_tag_command: None,
                 changelog_enabled: true,
+                changelog_range_arg: "--unreleased",
+                changelog_config_file: None,
                 changelog_output_file: "CHANGELOG.md",
                 tagging_enabled: true,
                 tagging_template_prefix_shell: "'v'",
                 tagging_template_suffix_shell: "''",
+                tagging_annotated: false,
+                tagging_sign: false,
+                tagger_name_shell: "'brel[bot]'",
+                tagger_email_shell: "'brel[bot]@users.noreply.github.com'",
+                tagging_message_shell: "\"Release ${tag}\"",
+                tagging_build_metadata_shell: None,
+                tagging_gpg_key_expr: "${{ secrets.BREL_TAG_GPG_PRIVATE_KEY }}",
+                tagging_gpg_passphrase_expr: "${{ secrets.BREL_TAG_GPG_PASSPHRASE }}",
             },
         )
         .unwrap();
@@ -201,6 +340,62 @@ mod tests {
             rendered
                 .contains("GITHUB_TOKEN tag pushes do not trigger downstream tag-push workflows.")
         );
+        assert!(rendered.contains("git tag \"${tag}\" \"${merge_commit_sha}\""));
+        assert!(rendered.contains("grep -Eq '<!-- managed-by: brel( base=[^ ]+)? -->'"));
+        assert!(!rendered.contains("tag -a"));
+        assert!(!rendered.contains("tag -s"));
+    }
+
+    #[test]
+    fn renders_annotated_and_signed_tag_commands() {
+        let render = |annotated: bool, sign: bool| {
+            render_workflow(
+                Provider::Github,
+                WorkflowTemplate::ReleasePr,
+                &WorkflowRenderContext {
+                    managed_marker: "managed-by: brel",
+                    default_branch: "main",
+                    release_pr_command: "brel release-pr",
+                    next_version_command: "brel next-version",
+                    github_token_expr: "${{ github.token }}",
+                    tagging_push_token_expr: "${{ secrets.BREL_TAG_PUSH_TOKEN }}",
+                    next_version_non_empty_expr: "${{ steps.next-version.outputs.version != '' }}",
+                    next_version_output_expr: "${{ steps.next-version.outputs.version }}",
+                    next_version_tag_output_expr: "v${{ steps.next-version.outputs.version }}",
+                    next_version_bump_output_expr: "${{ steps.next-version.outputs.bump }}",
+                    previous_tag_command: None,
+                    changelog_enabled: true,
+                    changelog_range_arg: "--unreleased",
+                    changelog_config_file: None,
+                    changelog_output_file: "CHANGELOG.md",
+                    tagging_enabled: true,
+                    tagging_template_prefix_shell: "'v'",
+                    tagging_template_suffix_shell: "''",
+                    tagging_annotated: annotated,
+                    tagging_sign: sign,
+                    tagger_name_shell: "'brel[bot]'",
+                    tagger_email_shell: "'brel[bot]@users.noreply.github.com'",
+                    tagging_message_shell: "\"Release ${tag}\"",
+                    tagging_build_metadata_shell: None,
+                    tagging_gpg_key_expr: "${{ secrets.BREL_TAG_GPG_PRIVATE_KEY }}",
+                    tagging_gpg_passphrase_expr: "${{ secrets.BREL_TAG_GPG_PASSPHRASE }}",
+                },
+            )
+            .unwrap()
+        };
+
+        let annotated = render(true, false);
+        assert!(annotated.contains("tag -a \"${tag}\" -m \"Release ${tag}\""));
+        assert!(annotated.contains("-c user.name='brel[bot]'"));
+        assert!(!annotated.contains("tag -s"));
+
+        let signed = render(false, true);
+        assert!(signed.contains("tag -s \"${tag}\" -m \"Release ${tag}\""));
+        assert!(signed.contains("tag -s \"${tag}\" -m \"Release ${tag}\""));
+        assert!(!signed.contains("tag -a"));
+        assert!(signed.contains("uses: crazy-max/ghaction-import-gpg@v6"));
+        assert!(signed.contains("gpg_private_key: ${{ secrets.BREL_TAG_GPG_PRIVATE_KEY }}"));
+        assert!(!annotated.contains("ghaction-import-gpg"));
     }
 
     #[test]
@@ -209,6 +404,7 @@ mod tests {
             Provider::Github,
             WorkflowTemplate::ReleasePr,
             &WorkflowRenderContext {
+                managed_marker: "managed-by: brel",
                 default_branch: "main",
                 release_pr_command: "brel release-pr",
                 next_version_command: "brel next-version",
@@ -217,11 +413,23 @@ mod tests {
                 next_version_non_empty_expr: "${{ steps.next-version.outputs.version != '' }}",
                 next_version_output_expr: "${{ steps.next-version.outputs.version }}",
                 next_version_tag_output_expr: "release-${{ steps.next-version.outputs.version }}",
+                next_version_bump_output_expr: "${{ steps.next-version.outputs.bump }}",
+                previous_tag_command: None,
                 changelog_enabled: true,
+                changelog_range_arg: "--unreleased",
+                changelog_config_file: None,
                 changelog_output_file: "CHANGELOG.md",
                 tagging_enabled: true,
                 tagging_template_prefix_shell: "release-",
                 tagging_template_suffix_shell: "''",
+                tagging_annotated: false,
+                tagging_sign: false,
+                tagger_name_shell: "'brel[bot]'",
+                tagger_email_shell: "'brel[bot]@users.noreply.github.com'",
+                tagging_message_shell: "\"Release ${tag}\"",
+                tagging_build_metadata_shell: None,
+                tagging_gpg_key_expr: "${{ secrets.BREL_TAG_GPG_PRIVATE_KEY }}",
+                tagging_gpg_passphrase_expr: "${{ secrets.BREL_TAG_GPG_PASSPHRASE }}",
             },
         )
         .unwrap();
@@ -234,6 +442,50 @@ mod tests {
         assert!(rendered.contains("suffix=''"));
     }
 
+    #[test]
+    fn renders_previous_tag_output_and_changelog_range() {
+        let rendered = render_workflow(
+            Provider::Github,
+            WorkflowTemplate::ReleasePr,
+            &WorkflowRenderContext {
+                managed_marker: "managed-by: brel",
+                default_branch: "main",
+                release_pr_command: "brel release-pr",
+                next_version_command: "brel next-version",
+                github_token_expr: "${{ github.token }}",
+                tagging_push_token_expr: "${{ secrets.BREL_TAG_PUSH_TOKEN }}",
+                next_version_non_empty_expr: "${{ steps.next-version.outputs.version != '' }}",
+                next_version_output_expr: "${{ steps.next-version.outputs.version }}",
+                next_version_tag_output_expr: "v${{ steps.next-version.outputs.version }}",
+                next_version_bump_output_expr: "${{ steps.next-version.outputs.bump }}",
+                previous_tag_command: Some("brel next-version --previous-tag"),
+                changelog_enabled: true,
+                changelog_range_arg: "${{ steps.next-version.outputs.previous_tag != '' && format('{0}..HEAD', steps.next-version.outputs.previous_tag) || '--unreleased' }}",
+                changelog_config_file: None,
+                changelog_output_file: "CHANGELOG.md",
+                tagging_enabled: false,
+                tagging_template_prefix_shell: "'v'",
+                tagging_template_suffix_shell: "''",
+                tagging_annotated: false,
+                tagging_sign: false,
+                tagger_name_shell: "'brel[bot]'",
+                tagger_email_shell: "'brel[bot]@users.noreply.github.com'",
+                tagging_message_shell: "\"Release ${tag}\"",
+                tagging_build_metadata_shell: None,
+                tagging_gpg_key_expr: "${{ secrets.BREL_TAG_GPG_PRIVATE_KEY }}",
+                tagging_gpg_passphrase_expr: "${{ secrets.BREL_TAG_GPG_PASSPHRASE }}",
+            },
+        )
+        .unwrap();
+
+        assert!(rendered.contains(
+            "previous_tag=\"$(brel next-version --previous-tag)\"\n          echo \"previous_tag=${previous_tag}\" >> \"${GITHUB_OUTPUT}\""
+        ));
+        assert!(rendered.contains(
+            "args: ${{ steps.next-version.outputs.previous_tag != '' && format('{0}..HEAD', steps.next-version.outputs.previous_tag) || '--unreleased' }} --tag v${{ steps.next-version.outputs.version }} --prepend CHANGELOG.md"
+        ));
+    }
+
     #[test]
     fn renders_default_release_pr_body_template() {
         let commits = [ReleasePrCommitContext {
@@ -246,14 +498,281 @@ mod tests {
                 tag: "v1.2.3",
                 base_branch: "main",
                 release_branch: "brel/release/v1.2.3",
+                managed_marker: "<!-- managed-by: brel base=main -->",
+                compare_url: None,
                 commits: &commits,
+                commit_count: 1,
+                more_commits: 0,
+                contributors: &[],
+                changelog_section: "",
+                counts: &BTreeMap::new(),
+                closes_issues: &[],
+                referenced_issues: &[],
             },
             None,
         )
         .unwrap();
 
-        assert!(rendered.contains(MANAGED_RELEASE_PR_MARKER));
+        assert!(rendered.starts_with("<!-- managed-by: brel base=main -->\n"));
         assert!(rendered.contains("Release v1.2.3"));
         assert!(rendered.contains("feat: add feature"));
+        assert!(!rendered.contains("Full diff:"));
+    }
+
+    #[test]
+    fn renders_commit_count_and_contributors_in_default_body() {
+        let commits = [
+            ReleasePrCommitContext {
+                sha_short: "abc1234",
+                subject: "feat: add feature",
+            },
+            ReleasePrCommitContext {
+                sha_short: "def5678",
+                subject: "fix: patch",
+            },
+        ];
+        let contributors = [ReleasePrContributorContext {
+            name: "Ada",
+            email: "ada@example.com",
+        }];
+        let rendered = render_release_pr_body(
+            &ReleasePrBodyContext {
+                version: "1.2.3",
+                tag: "v1.2.3",
+                base_branch: "main",
+                release_branch: "brel/release/v1.2.3",
+                managed_marker: "<!-- managed-by: brel base=main -->",
+                compare_url: None,
+                commits: &commits,
+                commit_count: commits.len(),
+                more_commits: 0,
+                contributors: &contributors,
+                changelog_section: "",
+                counts: &BTreeMap::new(),
+                closes_issues: &[],
+                referenced_issues: &[],
+            },
+            None,
+        )
+        .unwrap();
+
+        assert!(rendered.contains("2 commits from 1 contributor."));
+        assert!(rendered.contains("### Contributors\n- Ada\n"));
+        assert!(!rendered.contains("### Closes"));
+    }
+
+    #[test]
+    fn default_body_summarizes_commits_beyond_the_limit() {
+        let commits = [
+            ReleasePrCommitContext {
+                sha_short: "abc1234",
+                subject: "feat: add feature",
+            },
+            ReleasePrCommitContext {
+                sha_short: "def5678",
+                subject: "fix: patch",
+            },
+        ];
+        let rendered = render_release_pr_body(
+            &ReleasePrBodyContext {
+                version: "1.2.3",
+                tag: "v1.2.3",
+                base_branch: "main",
+                release_branch: "brel/release/v1.2.3",
+                managed_marker: "<!-- managed-by: brel base=main -->",
+                compare_url: None,
+                commits: &commits,
+                commit_count: 5,
+                more_commits: 3,
+                contributors: &[],
+                changelog_section: "",
+                counts: &BTreeMap::new(),
+                closes_issues: &[],
+                referenced_issues: &[],
+            },
+            None,
+        )
+        .unwrap();
+
+        assert!(rendered.contains("5 commits from 0 contributors."));
+        assert!(rendered.contains(
+            "- feat: add feature (abc1234)\n- fix: patch (def5678)\n- ...and 3 more commits\n"
+        ));
+    }
+
+    #[test]
+    fn renders_closes_section_for_referenced_issues() {
+        let closes_issues = ["#12".to_string(), "ABC-7".to_string()];
+        let referenced_issues = ["#56".to_string()];
+        let rendered = render_release_pr_body(
+            &ReleasePrBodyContext {
+                version: "1.2.3",
+                tag: "v1.2.3",
+                base_branch: "main",
+                release_branch: "brel/release/v1.2.3",
+                managed_marker: "<!-- managed-by: brel base=main -->",
+                compare_url: None,
+                commits: &[],
+                commit_count: 0,
+                more_commits: 0,
+                contributors: &[],
+                changelog_section: "",
+                counts: &BTreeMap::new(),
+                closes_issues: &closes_issues,
+                referenced_issues: &referenced_issues,
+            },
+            None,
+        )
+        .unwrap();
+
+        assert!(rendered.contains("### Closes\n- Closes #12\n- Closes ABC-7\n"));
+        assert!(rendered.contains("### References\n- #56\n"));
+        assert!(!rendered.contains("Closes #56"));
+    }
+
+    #[test]
+    fn renders_compare_url_when_previous_tag_exists() {
+        let rendered = render_release_pr_body(
+            &ReleasePrBodyContext {
+                version: "1.3.0",
+                tag: "v1.3.0",
+                base_branch: "main",
+                release_branch: "brel/release/v1.3.0",
+                managed_marker: "<!-- managed-by: brel base=main -->",
+                compare_url: Some(
+                    "https://github.com/acme/demo/compare/v1.2.3...brel/release/v1.3.0",
+                ),
+                commits: &[],
+                commit_count: 0,
+                more_commits: 0,
+                contributors: &[],
+                changelog_section: "",
+                counts: &BTreeMap::new(),
+                closes_issues: &[],
+                referenced_issues: &[],
+            },
+            None,
+        )
+        .unwrap();
+
+        assert!(rendered.contains(
+            "Full diff: https://github.com/acme/demo/compare/v1.2.3...brel/release/v1.3.0"
+        ));
+    }
+
+    #[test]
+    fn renders_default_release_pr_title() {
+        let rendered = render_release_pr_title(
+            &ReleasePrBodyContext {
+                version: "1.3.0",
+                tag: "v1.3.0",
+                base_branch: "main",
+                release_branch: "brel/release/v1.3.0",
+                managed_marker: "<!-- managed-by: brel base=main -->",
+                compare_url: None,
+                commits: &[],
+                commit_count: 0,
+                more_commits: 0,
+                contributors: &[],
+                changelog_section: "",
+                counts: &BTreeMap::new(),
+                closes_issues: &[],
+                referenced_issues: &[],
+            },
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(rendered, "Release v1.3.0");
+    }
+
+    #[test]
+    fn renders_commit_type_counts_in_custom_title() {
+        let counts = BTreeMap::from([
+            ("feat".to_string(), 2),
+            ("fix".to_string(), 1),
+            ("docs".to_string(), 3),
+        ]);
+        let rendered = render_release_pr_title(
+            &ReleasePrBodyContext {
+                version: "1.3.0",
+                tag: "v1.3.0",
+                base_branch: "main",
+                release_branch: "brel/release/v1.3.0",
+                managed_marker: "<!-- managed-by: brel base=main -->",
+                compare_url: None,
+                commits: &[],
+                commit_count: 0,
+                more_commits: 0,
+                contributors: &[],
+                changelog_section: "",
+                counts: &counts,
+                closes_issues: &[],
+                referenced_issues: &[],
+            },
+            Some("Release {{version}} ({{counts.feat}} features, {{counts.fix}} fix)"),
+        )
+        .unwrap();
+
+        assert_eq!(rendered, "Release 1.3.0 (2 features, 1 fix)");
+    }
+
+    #[test]
+    fn managed_markers_are_scoped_by_base_branch() {
+        let marker = "managed-by: brel";
+        let main_body = format!("{}\nbody", managed_release_pr_marker(marker, "main"));
+        let maintenance_body =
+            format!("{}\nbody", managed_release_pr_marker(marker, "release/1.x"));
+
+        assert!(is_managed_release_pr_body(&main_body, marker, "main"));
+        assert!(!is_managed_release_pr_body(
+            &main_body,
+            marker,
+            "release/1.x"
+        ));
+        assert!(is_managed_release_pr_body(
+            &maintenance_body,
+            marker,
+            "release/1.x"
+        ));
+        assert!(!is_managed_release_pr_body(
+            &maintenance_body,
+            marker,
+            "main"
+        ));
+    }
+
+    #[test]
+    fn legacy_unscoped_marker_is_still_recognized() {
+        let body = format!("{}\nbody", legacy_release_pr_marker("managed-by: brel"));
+        assert!(is_managed_release_pr_body(
+            &body,
+            "managed-by: brel",
+            "main"
+        ));
+        assert!(!is_managed_release_pr_body(
+            "plain body",
+            "managed-by: brel",
+            "main"
+        ));
+    }
+
+    #[test]
+    fn custom_marker_round_trips_through_body_detection() {
+        let marker = "managed-by: acme-release";
+        let body = format!("{}\nbody", managed_release_pr_marker(marker, "main"));
+
+        assert_eq!(body, "<!-- managed-by: acme-release base=main -->\nbody");
+        assert!(is_managed_release_pr_body(&body, marker, "main"));
+        assert!(!is_managed_release_pr_body(
+            &body,
+            "managed-by: brel",
+            "main"
+        ));
+        let default_body = format!(
+            "{}\nbody",
+            managed_release_pr_marker("managed-by: brel", "main")
+        );
+        assert!(!is_managed_release_pr_body(&default_body, marker, "main"));
     }
 }
@@ -0,0 +1,87 @@
+//! Scripted [`CommandRunner`] shared by the unit tests.
+
+use crate::release_pr::{CommandOutput, CommandRunner};
+use anyhow::Result;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone)]
+pub(crate) struct RecordedCall {
+    pub(crate) cwd: PathBuf,
+    pub(crate) program: String,
+    pub(crate) args: Vec<String>,
+    pub(crate) env: Vec<(String, String)>,
+}
+
+/// Answers each command with the next scripted output, in order, and records the call. Running
+/// out of responses is an error, which is also how tests simulate a command that cannot start.
+pub(crate) struct ScriptedRunner {
+    pub(crate) responses: VecDeque<CommandOutput>,
+    pub(crate) calls: Vec<RecordedCall>,
+    pub(crate) missing_programs: Vec<&'static str>,
+}
+
+impl ScriptedRunner {
+    pub(crate) fn new(responses: Vec<CommandOutput>) -> Self {
+        Self {
+            responses: responses.into(),
+            calls: Vec::new(),
+            missing_programs: Vec::new(),
+        }
+    }
+
+    pub(crate) fn programs(&self) -> Vec<&str> {
+        self.calls
+            .iter()
+            .map(|call| call.program.as_str())
+            .collect()
+    }
+}
+
+impl CommandRunner for ScriptedRunner {
+    fn run(
+        &mut self,
+        cwd: &Path,
+        program: &str,
+        args: &[String],
+        env: &[(String, String)],
+    ) -> Result<CommandOutput> {
+        self.calls.push(RecordedCall {
+            cwd: cwd.to_path_buf(),
+            program: program.to_string(),
+            args: args.to_vec(),
+            env: env.to_vec(),
+        });
+        self.responses
+            .pop_front()
+            .ok_or_else(|| anyhow::anyhow!("Missing scripted response for `{program}`"))
+    }
+
+    fn is_available(&mut self, program: &str) -> bool {
+        !self.missing_programs.contains(&program)
+    }
+}
+
+pub(crate) fn ok(stdout: &str) -> CommandOutput {
+    CommandOutput {
+        status: 0,
+        stdout: stdout.to_string(),
+        stderr: String::new(),
+    }
+}
+
+pub(crate) fn status(code: i32) -> CommandOutput {
+    CommandOutput {
+        status: code,
+        stdout: String::new(),
+        stderr: String::new(),
+    }
+}
+
+pub(crate) fn err_status(code: i32, stderr: &str) -> CommandOutput {
+    CommandOutput {
+        status: code,
+        stdout: String::new(),
+        stderr: stderr.to_string(),
+    }
+}
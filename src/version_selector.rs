@@ -1,3 +1,4 @@
+use crate::error::BrelError;
 use anyhow::{Context, Result, bail};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -17,7 +18,11 @@ pub enum SegmentQualifier {
     Filter { field: String, value: String },
 }
 
-pub fn parse_selector(value: &str) -> Result<VersionSelector> {
+pub fn parse_selector(value: &str) -> Result<VersionSelector, BrelError> {
+    parse_selector_segments(value).map_err(BrelError::Selector)
+}
+
+fn parse_selector_segments(value: &str) -> Result<VersionSelector> {
     let trimmed = value.trim();
     if trimmed.is_empty() {
         bail!("Version selector cannot be empty.");
@@ -1,7 +1,13 @@
-use crate::config::VersionFileFormat;
+use crate::config::{ReleasePrConfig, SelectorFormats, VersionFileFormat};
+use crate::error::BrelError;
+use crate::path_glob;
+use crate::release_pr::{self, CommandRunner};
 use crate::version_selector::{SegmentQualifier, VersionSelector, parse_selector};
 use anyhow::{Context, Result, bail};
+use rayon::prelude::*;
+use serde::Serialize;
 use serde_json::Value as JsonValue;
+use similar::TextDiff;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -11,6 +17,129 @@ use toml_edit::{DocumentMut, Item, Table, Value as TomlEditValue};
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UpdateReport {
     pub changed_files: Vec<PathBuf>,
+    pub warnings: Vec<String>,
+    pub diffs: Vec<FileDiff>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDiff {
+    pub path: PathBuf,
+    pub unified: String,
+}
+
+/// How selectors must resolve, taken from `release_pr.allow_missing_selectors` and
+/// `release_pr.strict_single_match`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SelectorMatching {
+    /// Skip a JSON/TOML selector that matches nothing, with a warning, instead of failing.
+    pub allow_missing: bool,
+    /// Fail when a JSON/TOML selector matches more than one value.
+    pub single_match: bool,
+}
+
+impl SelectorMatching {
+    pub fn from_config(release_pr: &ReleasePrConfig) -> Self {
+        Self {
+            allow_missing: release_pr.allow_missing_selectors,
+            single_match: release_pr.strict_single_match,
+        }
+    }
+}
+
+/// Formats forced by `release_pr.format_overrides` (per file) and by `{ selector, format }`
+/// entries in `release_pr.version_updates` (per selector, taking precedence).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatOverrides<'a> {
+    pub files: &'a BTreeMap<String, VersionFileFormat>,
+    pub selectors: &'a SelectorFormats,
+}
+
+static NO_FILE_FORMATS: BTreeMap<String, VersionFileFormat> = BTreeMap::new();
+static NO_SELECTOR_FORMATS: SelectorFormats = BTreeMap::new();
+
+/// No overrides: every format is inferred from the file extension.
+impl Default for FormatOverrides<'_> {
+    fn default() -> Self {
+        Self {
+            files: &NO_FILE_FORMATS,
+            selectors: &NO_SELECTOR_FORMATS,
+        }
+    }
+}
+
+impl<'a> FormatOverrides<'a> {
+    pub fn from_config(release_pr: &'a ReleasePrConfig) -> Self {
+        Self {
+            files: &release_pr.format_overrides,
+            selectors: &release_pr.selector_formats,
+        }
+    }
+
+    /// Per-file overrides only, with no per-selector formats.
+    pub fn files(files: &'a BTreeMap<String, VersionFileFormat>) -> Self {
+        Self {
+            files,
+            selectors: &NO_SELECTOR_FORMATS,
+        }
+    }
+
+    fn for_selector(&self, relative_path: &str, selector: &str) -> Option<VersionFileFormat> {
+        resolve_path_entry(relative_path, self.selectors)
+            .and_then(|formats| formats.get(selector.trim()))
+            .copied()
+            .or_else(|| resolve_format_override(relative_path, self.files))
+    }
+}
+
+fn resolve_format_override(
+    relative_path: &str,
+    format_overrides: &BTreeMap<String, VersionFileFormat>,
+) -> Option<VersionFileFormat> {
+    resolve_path_entry(relative_path, format_overrides).copied()
+}
+
+/// What the selectors are set to: the bare version, or the rendered release tag in files
+/// matched by `release_pr.tag_files` (for example a `VERSION` file holding `v1.2.3`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VersionValues<'a> {
+    pub version: &'a str,
+    pub tag: &'a str,
+    pub tag_files: &'a [String],
+}
+
+impl<'a> VersionValues<'a> {
+    /// Writes `version` to every file.
+    pub fn bare(version: &'a str) -> Self {
+        Self {
+            version,
+            tag: version,
+            tag_files: &[],
+        }
+    }
+
+    pub fn from_config(version: &'a str, tag: &'a str, release_pr: &'a ReleasePrConfig) -> Self {
+        Self {
+            version,
+            tag,
+            tag_files: &release_pr.tag_files,
+        }
+    }
+
+    fn for_file(&self, relative_path: &str) -> &'a str {
+        if is_tag_file(self.tag_files, relative_path) {
+            self.tag
+        } else {
+            self.version
+        }
+    }
+}
+
+/// Whether `release_pr.tag_files` lists `relative_path`, by exact path or glob.
+pub(crate) fn is_tag_file(tag_files: &[String], relative_path: &str) -> bool {
+    tag_files.iter().any(|pattern| {
+        pattern == relative_path
+            || (path_glob::is_glob_pattern(pattern) && path_glob::matches(pattern, relative_path))
+    })
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -21,39 +150,367 @@ enum PathStep {
 
 pub fn apply_version_updates(
     repo_root: &Path,
-    next_version: &str,
+    values: VersionValues<'_>,
+    version_updates: &BTreeMap<String, Vec<String>>,
+    format_overrides: &BTreeMap<String, VersionFileFormat>,
+    matching: SelectorMatching,
+) -> Result<UpdateReport, BrelError> {
+    apply_version_updates_with_formats(
+        repo_root,
+        values,
+        version_updates,
+        FormatOverrides::files(format_overrides),
+        matching,
+    )
+}
+
+/// Like [`apply_version_updates`], but also honors per-selector formats from
+/// `{ selector, format }` entries in `release_pr.version_updates`.
+pub fn apply_version_updates_with_formats(
+    repo_root: &Path,
+    values: VersionValues<'_>,
+    version_updates: &BTreeMap<String, Vec<String>>,
+    format_overrides: FormatOverrides<'_>,
+    matching: SelectorMatching,
+) -> Result<UpdateReport, BrelError> {
+    run_version_updates(
+        repo_root,
+        values,
+        version_updates,
+        format_overrides,
+        matching,
+        true,
+        false,
+    )
+    .map_err(|err| BrelError::classify(err, BrelError::VersionUpdate))
+}
+
+pub fn preview_version_updates(
+    repo_root: &Path,
+    values: VersionValues<'_>,
     version_updates: &BTreeMap<String, Vec<String>>,
     format_overrides: &BTreeMap<String, VersionFileFormat>,
+    matching: SelectorMatching,
+    include_diffs: bool,
+) -> Result<UpdateReport, BrelError> {
+    preview_version_updates_with_formats(
+        repo_root,
+        values,
+        version_updates,
+        FormatOverrides::files(format_overrides),
+        matching,
+        include_diffs,
+    )
+}
+
+/// Like [`preview_version_updates`], but also honors per-selector formats.
+pub fn preview_version_updates_with_formats(
+    repo_root: &Path,
+    values: VersionValues<'_>,
+    version_updates: &BTreeMap<String, Vec<String>>,
+    format_overrides: FormatOverrides<'_>,
+    matching: SelectorMatching,
+    include_diffs: bool,
+) -> Result<UpdateReport, BrelError> {
+    run_version_updates(
+        repo_root,
+        values,
+        version_updates,
+        format_overrides,
+        matching,
+        false,
+        include_diffs,
+    )
+    .map_err(|err| BrelError::classify(err, BrelError::VersionUpdate))
+}
+
+pub fn expand_version_update_globs(
+    runner: &mut dyn CommandRunner,
+    repo_root: &Path,
+    version_updates: &BTreeMap<String, Vec<String>>,
+    exclude: &[String],
+    allow_empty_globs: bool,
+) -> Result<BTreeMap<String, Vec<String>>, BrelError> {
+    expand_globs(
+        runner,
+        repo_root,
+        version_updates,
+        exclude,
+        allow_empty_globs,
+    )
+    .map_err(|err| BrelError::classify(err, BrelError::VersionUpdate))
+}
+
+fn expand_globs(
+    runner: &mut dyn CommandRunner,
+    repo_root: &Path,
+    version_updates: &BTreeMap<String, Vec<String>>,
+    exclude: &[String],
+    allow_empty_globs: bool,
+) -> Result<BTreeMap<String, Vec<String>>> {
+    let globs = version_updates
+        .keys()
+        .filter(|key| path_glob::is_glob_pattern(key))
+        .collect::<Vec<_>>();
+    if globs.is_empty() {
+        return Ok(version_updates.clone());
+    }
+
+    let repo_files = tracked_repo_files(runner, repo_root)?;
+
+    let mut expanded = BTreeMap::new();
+    let mut matched_by = BTreeMap::<String, &str>::new();
+    for pattern in &globs {
+        let mut matched_any = false;
+        for file in repo_files.iter().filter(|file| {
+            path_glob::matches(pattern, file)
+                && !exclude
+                    .iter()
+                    .any(|excluded| path_glob::matches(excluded, file))
+        }) {
+            matched_any = true;
+            if version_updates.contains_key(file) {
+                continue;
+            }
+            // Like `format_overrides`, the longest matching glob wins.
+            let replace = matched_by
+                .get(file)
+                .is_none_or(|current| pattern.len() > current.len());
+            if replace {
+                matched_by.insert(file.clone(), pattern.as_str());
+                expanded.insert(file.clone(), version_updates[pattern.as_str()].clone());
+            }
+        }
+        if !matched_any && !allow_empty_globs {
+            bail!(
+                "`release_pr.version_updates` glob `{pattern}` did not match any files outside \
+                 `release_pr.exclude`. Set `release_pr.allow_empty_globs = true` to allow this."
+            );
+        }
+    }
+
+    for (path, selectors) in version_updates {
+        if !path_glob::is_glob_pattern(path) {
+            expanded.insert(path.clone(), selectors.clone());
+        }
+    }
+    Ok(expanded)
+}
+
+/// Lists the files git tracks under `repo_root`, so globs never reach ignored or untracked
+/// paths such as `node_modules` or `target`.
+fn tracked_repo_files(runner: &mut dyn CommandRunner, repo_root: &Path) -> Result<Vec<String>> {
+    let output = release_pr::run_checked(
+        runner,
+        repo_root,
+        "git",
+        vec!["ls-files".to_string(), "-z".to_string()],
+        &[],
+        "Failed to list repository files for `release_pr.version_updates` globs.",
+    )?;
+    // Symlinked directories and submodules are listed as entries but hold no version file.
+    Ok(output
+        .stdout
+        .split('\0')
+        .filter(|path| !path.is_empty() && !repo_root.join(path).is_dir())
+        .map(str::to_string)
+        .collect())
+}
+
+fn run_version_updates(
+    repo_root: &Path,
+    values: VersionValues<'_>,
+    version_updates: &BTreeMap<String, Vec<String>>,
+    format_overrides: FormatOverrides<'_>,
+    matching: SelectorMatching,
+    write: bool,
+    include_diffs: bool,
 ) -> Result<UpdateReport> {
-    let mut changed_files = Vec::new();
+    // Files are independent, so read/parse/resolve them in parallel. Collecting keeps the
+    // `version_updates` order, which makes the first error and the report deterministic.
+    let planned = version_updates
+        .par_iter()
+        .map(|(relative_path, selectors)| {
+            plan_file_update(
+                repo_root,
+                relative_path,
+                selectors,
+                values.for_file(relative_path),
+                format_overrides,
+                matching,
+            )
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .collect::<Result<Vec<_>>>()?;
 
-    for (relative_path, selectors) in version_updates {
-        let file_path = repo_root.join(relative_path);
-        if !file_path.exists() {
-            bail!("Configured version update file `{relative_path}` was not found.");
+    let mut changed_files = Vec::new();
+    let mut warnings = Vec::new();
+    let mut diffs = Vec::new();
+    for update in planned {
+        warnings.extend(update.warnings);
+        let Some(output) = update.output else {
+            continue;
+        };
+        if include_diffs {
+            diffs.push(FileDiff {
+                path: PathBuf::from(update.relative_path),
+                unified: unified_diff(update.relative_path, &update.content, &output),
+            });
         }
+        if write {
+            fs::write(&update.file_path, output)
+                .with_context(|| format!("Failed to write `{}`.", update.file_path.display()))?;
+        }
+        changed_files.push(PathBuf::from(update.relative_path));
+    }
 
-        let format =
-            detect_file_format(relative_path, format_overrides.get(relative_path).copied())?;
-        let content = fs::read_to_string(&file_path)
-            .with_context(|| format!("Failed to read `{}`.", file_path.display()))?;
+    Ok(UpdateReport {
+        changed_files,
+        warnings,
+        diffs,
+    })
+}
 
-        let parsed_selectors = parse_selectors(selectors, &file_path)?;
-        let changed = match format {
-            VersionFileFormat::Json => {
-                update_json_file(&file_path, &content, &parsed_selectors, next_version)?
-            }
-            VersionFileFormat::Toml => {
-                update_toml_file(&file_path, &content, &parsed_selectors, next_version)?
+struct PlannedFileUpdate<'a> {
+    relative_path: &'a str,
+    file_path: PathBuf,
+    content: String,
+    output: Option<String>,
+    warnings: Vec<String>,
+}
+
+fn plan_file_update<'a>(
+    repo_root: &Path,
+    relative_path: &'a str,
+    selectors: &[String],
+    next_version: &str,
+    format_overrides: FormatOverrides<'_>,
+    matching: SelectorMatching,
+) -> Result<PlannedFileUpdate<'a>> {
+    let file_path = repo_root.join(relative_path);
+    if !file_path.exists() {
+        return Err(BrelError::MissingFile {
+            path: relative_path.to_string(),
+        }
+        .into());
+    }
+    ensure_within_repo(repo_root, &file_path, relative_path)?;
+
+    // Selectors are grouped by format in first-seen order; each group edits the previous output.
+    let mut format_groups: Vec<(VersionFileFormat, Vec<String>)> = Vec::new();
+    for selector in selectors {
+        let format = detect_file_format(
+            relative_path,
+            format_overrides.for_selector(relative_path, selector),
+        )?;
+        match format_groups.iter_mut().find(|(known, _)| *known == format) {
+            Some((_, grouped)) => grouped.push(selector.clone()),
+            None => format_groups.push((format, vec![selector.clone()])),
+        }
+    }
+    let content = fs::read_to_string(&file_path)
+        .with_context(|| format!("Failed to read `{}`.", file_path.display()))?;
+
+    let mut warnings = Vec::new();
+    let mut output: Option<String> = None;
+    for (format, selectors) in &format_groups {
+        let current = output.as_deref().unwrap_or(&content);
+        let updated = match format {
+            VersionFileFormat::Json => update_json_file(
+                &file_path,
+                current,
+                &parse_selectors(selectors, &file_path)?,
+                next_version,
+                matching,
+                &mut warnings,
+            )?,
+            VersionFileFormat::Toml => update_toml_file(
+                &file_path,
+                current,
+                &parse_selectors(selectors, &file_path)?,
+                next_version,
+                matching,
+                &mut warnings,
+            )?,
+            VersionFileFormat::Anchored => {
+                update_anchored_file(&file_path, current, selectors, next_version)?
             }
         };
+        if updated.is_some() {
+            output = updated;
+        }
+    }
 
-        if changed {
-            changed_files.push(PathBuf::from(relative_path));
+    Ok(PlannedFileUpdate {
+        relative_path,
+        file_path,
+        content,
+        output,
+        warnings,
+    })
+}
+
+fn ensure_within_repo(repo_root: &Path, file_path: &Path, relative_path: &str) -> Result<()> {
+    let canonical_root = repo_root
+        .canonicalize()
+        .with_context(|| format!("Failed to resolve `{}`.", repo_root.display()))?;
+    let canonical_file = file_path
+        .canonicalize()
+        .with_context(|| format!("Failed to resolve `{}`.", file_path.display()))?;
+    if !canonical_file.starts_with(&canonical_root) {
+        bail!(
+            "Configured version update file `{relative_path}` resolves to `{}`, which is outside \
+             the repository.",
+            canonical_file.display()
+        );
+    }
+    Ok(())
+}
+
+fn unified_diff(relative_path: &str, before: &str, after: &str) -> String {
+    TextDiff::from_lines(before, after)
+        .unified_diff()
+        .context_radius(3)
+        .header(&format!("a/{relative_path}"), &format!("b/{relative_path}"))
+        .to_string()
+}
+
+fn record_overlaps<'a>(
+    claimed: &mut BTreeMap<Vec<PathStep>, &'a str>,
+    target_paths: &[Vec<PathStep>],
+    selector_text: &'a str,
+    file_path: &Path,
+    warnings: &mut Vec<String>,
+) {
+    for path in target_paths {
+        match claimed.get(path) {
+            Some(previous) => warnings.push(format!(
+                "Selectors `{previous}` and `{selector_text}` both resolve to `{}` in `{}`.",
+                format_path(path),
+                file_path.display()
+            )),
+            None => {
+                claimed.insert(path.clone(), selector_text);
+            }
         }
     }
+}
 
-    Ok(UpdateReport { changed_files })
+fn format_path(path: &[PathStep]) -> String {
+    let mut rendered = String::new();
+    for step in path {
+        match step {
+            PathStep::Key(key) => {
+                if !rendered.is_empty() {
+                    rendered.push('.');
+                }
+                rendered.push_str(key);
+            }
+            PathStep::Index(index) => rendered.push_str(&format!("[{index}]")),
+        }
+    }
+    rendered
 }
 
 fn parse_selectors(
@@ -74,7 +531,25 @@ fn parse_selectors(
     Ok(parsed)
 }
 
-fn detect_file_format(
+// An exact path wins, then the longest matching glob.
+fn resolve_path_entry<'m, T>(
+    relative_path: &str,
+    entries: &'m BTreeMap<String, T>,
+) -> Option<&'m T> {
+    if let Some(entry) = entries.get(relative_path) {
+        return Some(entry);
+    }
+
+    entries
+        .iter()
+        .filter(|(pattern, _)| {
+            path_glob::is_glob_pattern(pattern) && path_glob::matches(pattern, relative_path)
+        })
+        .max_by_key(|(pattern, _)| pattern.len())
+        .map(|(_, entry)| entry)
+}
+
+pub(crate) fn detect_file_format(
     relative_path: &str,
     override_format: Option<VersionFileFormat>,
 ) -> Result<VersionFileFormat> {
@@ -90,25 +565,235 @@ fn detect_file_format(
     {
         Some("json") => Ok(VersionFileFormat::Json),
         Some("toml") => Ok(VersionFileFormat::Toml),
+        Some("md" | "markdown") => Ok(VersionFileFormat::Anchored),
         _ => bail!(
             "Cannot infer file format for `{relative_path}`. Use `release_pr.format_overrides` \
-             with `json` or `toml`."
+             with `json`, `toml`, or `anchored`."
         ),
     }
 }
 
+fn update_anchored_file(
+    file_path: &Path,
+    content: &str,
+    selectors: &[String],
+    next_version: &str,
+) -> Result<Option<String>> {
+    let mut output = content.to_string();
+    for raw_selector in selectors {
+        let name = raw_selector.trim();
+        if name.is_empty() || name.contains("-->") || name.chars().any(char::is_whitespace) {
+            bail!(
+                "Invalid anchor name `{name}` while updating `{}`.",
+                file_path.display()
+            );
+        }
+        output = replace_anchored_spans(&output, name, next_version).with_context(|| {
+            format!(
+                "While updating anchor `{name}` in `{}`.",
+                file_path.display()
+            )
+        })?;
+    }
+
+    if output == content {
+        return Ok(None);
+    }
+    Ok(Some(output))
+}
+
+fn replace_anchored_spans(content: &str, name: &str, next_version: &str) -> Result<String> {
+    let mut output = String::with_capacity(content.len());
+    let mut copied_until = 0;
+    for (start, end) in find_anchored_spans(content, name)? {
+        output.push_str(&content[copied_until..start]);
+        output.push_str(next_version);
+        copied_until = end;
+    }
+    output.push_str(&content[copied_until..]);
+    Ok(output)
+}
+
+// Returns the byte ranges of the text between each `<!-- brel:NAME -->` ... `<!-- /brel:NAME -->` pair.
+fn find_anchored_spans(content: &str, name: &str) -> Result<Vec<(usize, usize)>> {
+    let open = format!("<!-- brel:{name} -->");
+    let close = format!("<!-- /brel:{name} -->");
+
+    let mut spans = Vec::new();
+    let mut offset = 0;
+    loop {
+        let rest = &content[offset..];
+        let next_open = rest.find(&open);
+        let next_close = rest.find(&close);
+        match (next_open, next_close) {
+            (None, None) => break,
+            (Some(open_at), close_at) if close_at.is_none_or(|close_at| open_at < close_at) => {
+                let inner_start = open_at + open.len();
+                let Some(inner_len) = rest[inner_start..].find(&close) else {
+                    bail!("Marker `{open}` has no matching `{close}`.");
+                };
+                let inner = &rest[inner_start..inner_start + inner_len];
+                if inner.contains(&open) {
+                    bail!("Marker `{open}` is opened again before `{close}`.");
+                }
+                if inner.contains('\n') {
+                    bail!("Text between `{open}` and `{close}` must stay on one line.");
+                }
+                spans.push((offset + inner_start, offset + inner_start + inner_len));
+                offset += inner_start + inner_len + close.len();
+            }
+            _ => bail!("Found `{close}` without a preceding `{open}`."),
+        }
+    }
+
+    if spans.is_empty() {
+        bail!("No `{open}` ... `{close}` markers found.");
+    }
+    Ok(spans)
+}
+
+/// Reads the version string a selector points at, for `release_pr.version_source = "file:..."`.
+/// Every value the selector matches must hold the same version.
+pub fn read_version_from_file(
+    repo_root: &Path,
+    relative_path: &str,
+    selector_text: &str,
+    format_overrides: &BTreeMap<String, VersionFileFormat>,
+) -> Result<String> {
+    read_version_from_file_with_formats(
+        repo_root,
+        relative_path,
+        selector_text,
+        FormatOverrides::files(format_overrides),
+    )
+}
+
+/// Like [`read_version_from_file`], but also honors per-selector formats.
+pub fn read_version_from_file_with_formats(
+    repo_root: &Path,
+    relative_path: &str,
+    selector_text: &str,
+    format_overrides: FormatOverrides<'_>,
+) -> Result<String> {
+    let file_path = repo_root.join(relative_path);
+    if !file_path.exists() {
+        bail!("Version source file `{relative_path}` was not found.");
+    }
+    ensure_within_repo(repo_root, &file_path, relative_path)?;
+
+    let format = detect_file_format(
+        relative_path,
+        format_overrides.for_selector(relative_path, selector_text),
+    )?;
+    let content = fs::read_to_string(&file_path)
+        .with_context(|| format!("Failed to read `{}`.", file_path.display()))?;
+
+    let values = match format {
+        VersionFileFormat::Json => {
+            let root: JsonValue = serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse JSON file `{}`.", file_path.display()))?;
+            let selector = parse_selectors(&[selector_text.to_string()], &file_path)?
+                .remove(0)
+                .1;
+            resolve_json_paths(&root, selector_text, &selector, &file_path)?
+                .iter()
+                .map(|path| match json_value_at_path(&root, path) {
+                    Some(JsonValue::String(value)) => Ok(value.clone()),
+                    Some(other) => bail!(
+                        "Selector `{selector_text}` in `{}` matched `{}` at `{}`, expected a string.",
+                        file_path.display(),
+                        json_type_name(other),
+                        format_path(path)
+                    ),
+                    None => bail!(
+                        "Selector `{selector_text}` in `{}` matched no value at `{}`.",
+                        file_path.display(),
+                        format_path(path)
+                    ),
+                })
+                .collect::<Result<Vec<_>>>()?
+        }
+        VersionFileFormat::Toml => {
+            let root: TomlValue = content
+                .parse()
+                .with_context(|| format!("Failed to parse TOML file `{}`.", file_path.display()))?;
+            let selector = parse_selectors(&[selector_text.to_string()], &file_path)?
+                .remove(0)
+                .1;
+            let mut values = Vec::new();
+            for path in resolve_toml_paths(&root, selector_text, &selector, &file_path)? {
+                ensure_not_workspace_inherited(&root, &path, selector_text, &file_path)?;
+                match toml_value_at_path(&root, &path) {
+                    Some(TomlValue::String(value)) => values.push(value.clone()),
+                    Some(other) => bail!(
+                        "Selector `{selector_text}` in `{}` matched `{}` at `{}`, expected a string.",
+                        file_path.display(),
+                        other.type_str(),
+                        format_path(&path)
+                    ),
+                    None => bail!(
+                        "Selector `{selector_text}` in `{}` matched no value at `{}`.",
+                        file_path.display(),
+                        format_path(&path)
+                    ),
+                }
+            }
+            values
+        }
+        VersionFileFormat::Anchored => find_anchored_spans(&content, selector_text)
+            .with_context(|| {
+                format!(
+                    "While reading anchor `{selector_text}` in `{}`.",
+                    file_path.display()
+                )
+            })?
+            .into_iter()
+            .map(|(start, end)| content[start..end].trim().to_string())
+            .collect(),
+    };
+
+    let Some(first) = values.first() else {
+        bail!(
+            "Selector `{selector_text}` in `{}` matched no values.",
+            file_path.display()
+        );
+    };
+    if let Some(other) = values.iter().find(|value| *value != first) {
+        bail!(
+            "Selector `{selector_text}` in `{}` matched different versions (`{first}` and `{other}`).",
+            file_path.display()
+        );
+    }
+    Ok(first.clone())
+}
+
 fn update_json_file(
     file_path: &Path,
     content: &str,
     selectors: &[(String, VersionSelector)],
     next_version: &str,
-) -> Result<bool> {
+    matching: SelectorMatching,
+    warnings: &mut Vec<String>,
+) -> Result<Option<String>> {
     let mut value: JsonValue = serde_json::from_str(content)
         .with_context(|| format!("Failed to parse JSON file `{}`.", file_path.display()))?;
 
     let mut changed = false;
+    let mut claimed = BTreeMap::new();
     for (selector_text, selector) in selectors {
         let target_paths = resolve_json_paths(&value, selector_text, selector, file_path)?;
+        if target_paths.is_empty() {
+            skip_missing_selector(selector_text, file_path, matching.allow_missing, warnings)?;
+            continue;
+        }
+        ensure_single_match(&target_paths, selector_text, file_path, matching)?;
+        record_overlaps(
+            &mut claimed,
+            &target_paths,
+            selector_text,
+            file_path,
+            warnings,
+        );
         for path in &target_paths {
             changed |=
                 set_json_string_at_path(&mut value, path, next_version, selector_text, file_path)
@@ -122,15 +807,143 @@ fn update_json_file(
     }
 
     if !changed {
-        return Ok(false);
+        return Ok(None);
     }
 
-    let mut output = serde_json::to_string_pretty(&value)
+    let output = serialize_json_like(&value, content)
         .with_context(|| format!("Failed to serialize JSON file `{}`.", file_path.display()))?;
-    output.push('\n');
-    fs::write(file_path, output)
-        .with_context(|| format!("Failed to write `{}`.", file_path.display()))?;
-    Ok(true)
+    verify_json_output(&output, &claimed, next_version, file_path)?;
+    Ok(Some(output))
+}
+
+// A selector that resolves to nothing is an error unless `release_pr.allow_missing_selectors`
+// turns it into a reported skip.
+fn skip_missing_selector(
+    selector_text: &str,
+    file_path: &Path,
+    allow_missing: bool,
+    warnings: &mut Vec<String>,
+) -> Result<()> {
+    if !allow_missing {
+        bail!(
+            "Selector `{selector_text}` matched no values in `{}`.",
+            file_path.display()
+        );
+    }
+    warnings.push(format!(
+        "Selector `{selector_text}` matched no values in `{}`; skipping it because \
+         `release_pr.allow_missing_selectors` is enabled.",
+        file_path.display()
+    ));
+    Ok(())
+}
+
+fn ensure_single_match(
+    target_paths: &[Vec<PathStep>],
+    selector_text: &str,
+    file_path: &Path,
+    matching: SelectorMatching,
+) -> Result<()> {
+    if matching.single_match && target_paths.len() > 1 {
+        bail!(
+            "Selector `{selector_text}` matched {} values in `{}` ({}), but \
+             `release_pr.strict_single_match` allows only one.",
+            target_paths.len(),
+            file_path.display(),
+            target_paths
+                .iter()
+                .map(|path| format!("`{}`", format_path(path)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    Ok(())
+}
+
+fn verify_json_output(
+    output: &str,
+    targets: &BTreeMap<Vec<PathStep>, &str>,
+    next_version: &str,
+    file_path: &Path,
+) -> Result<()> {
+    let written: JsonValue = serde_json::from_str(output).with_context(|| {
+        format!(
+            "Failed to re-read updated JSON file `{}`.",
+            file_path.display()
+        )
+    })?;
+    for (path, selector_text) in targets {
+        let actual = json_value_at_path(&written, path).and_then(JsonValue::as_str);
+        ensure_written_version(actual, path, selector_text, next_version, file_path)?;
+    }
+    Ok(())
+}
+
+fn ensure_written_version(
+    actual: Option<&str>,
+    path: &[PathStep],
+    selector_text: &str,
+    next_version: &str,
+    file_path: &Path,
+) -> Result<()> {
+    if actual == Some(next_version) {
+        return Ok(());
+    }
+    let read_back = match actual {
+        Some(value) => format!("`{value}`"),
+        None => "a missing or non-string value".to_string(),
+    };
+    bail!(
+        "Selector `{selector_text}` in `{}` should have set `{}` to `{next_version}`, but it \
+         reads back as {read_back}.",
+        file_path.display(),
+        format_path(path)
+    );
+}
+
+fn serialize_json_like(value: &JsonValue, original: &str) -> Result<String> {
+    let body = original.trim_end();
+    let mut output = if body.contains('\n') {
+        let indent = detect_json_indent(body);
+        let mut buffer = Vec::new();
+        let formatter = serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes());
+        let mut serializer = serde_json::Serializer::with_formatter(&mut buffer, formatter);
+        value.serialize(&mut serializer)?;
+        String::from_utf8(buffer)?
+    } else {
+        serde_json::to_string(value)?
+    };
+
+    if original.ends_with('\n') {
+        output.push('\n');
+    }
+    Ok(restore_line_endings(output, original))
+}
+
+// Serializers emit `\n`; rewrite the output with CRLF when that dominates the original file.
+fn restore_line_endings(output: String, original: &str) -> String {
+    let crlf = original.matches("\r\n").count();
+    let lf = original.matches('\n').count() - crlf;
+    if crlf > lf {
+        output.replace("\r\n", "\n").replace('\n', "\r\n")
+    } else {
+        output
+    }
+}
+
+fn detect_json_indent(content: &str) -> String {
+    content
+        .lines()
+        .skip(1)
+        .find_map(|line| {
+            let trimmed = line.trim_start_matches([' ', '\t']);
+            if trimmed.is_empty() {
+                return None;
+            }
+            let indent = &line[..line.len() - trimmed.len()];
+            (!indent.is_empty()).then(|| indent.to_string())
+        })
+        .unwrap_or_else(|| "  ".to_string())
 }
 
 fn resolve_json_paths(
@@ -217,13 +1030,6 @@ fn resolve_json_paths(
         current_paths = next_paths.into_iter().collect();
     }
 
-    if current_paths.is_empty() {
-        bail!(
-            "Selector `{selector_text}` matched no values in `{}`.",
-            file_path.display()
-        );
-    }
-
     Ok(current_paths)
 }
 
@@ -286,7 +1092,8 @@ fn set_json_string_at_path(
 
     let Some(existing_value) = current.as_str() else {
         bail!(
-            "Selector `{selector_text}` matched a non-string JSON value in `{}`.",
+            "Selector `{selector_text}` matched a non-string JSON value ({}) in `{}`.",
+            json_type_name(current),
             file_path.display()
         );
     };
@@ -304,7 +1111,9 @@ fn update_toml_file(
     content: &str,
     selectors: &[(String, VersionSelector)],
     next_version: &str,
-) -> Result<bool> {
+    matching: SelectorMatching,
+    warnings: &mut Vec<String>,
+) -> Result<Option<String>> {
     let source_value: TomlValue = content
         .parse()
         .with_context(|| format!("Failed to parse TOML file `{}`.", file_path.display()))?;
@@ -313,9 +1122,23 @@ fn update_toml_file(
         .with_context(|| format!("Failed to parse TOML file `{}`.", file_path.display()))?;
 
     let mut changed = false;
+    let mut claimed = BTreeMap::new();
     for (selector_text, selector) in selectors {
         let target_paths = resolve_toml_paths(&source_value, selector_text, selector, file_path)?;
+        if target_paths.is_empty() {
+            skip_missing_selector(selector_text, file_path, matching.allow_missing, warnings)?;
+            continue;
+        }
+        ensure_single_match(&target_paths, selector_text, file_path, matching)?;
+        record_overlaps(
+            &mut claimed,
+            &target_paths,
+            selector_text,
+            file_path,
+            warnings,
+        );
         for path in &target_paths {
+            ensure_not_workspace_inherited(&source_value, path, selector_text, file_path)?;
             changed |= set_toml_string_at_path(
                 document.as_item_mut(),
                 path,
@@ -333,16 +1156,39 @@ fn update_toml_file(
     }
 
     if !changed {
-        return Ok(false);
+        return Ok(None);
     }
 
     let mut output = document.to_string();
-    if !output.ends_with('\n') {
-        output.push('\n');
+    match (content.ends_with('\n'), output.ends_with('\n')) {
+        (true, false) => output.push('\n'),
+        (false, true) => {
+            output.pop();
+        }
+        _ => {}
     }
-    fs::write(file_path, output)
-        .with_context(|| format!("Failed to write `{}`.", file_path.display()))?;
-    Ok(true)
+    let output = restore_line_endings(output, content);
+    verify_toml_output(&output, &claimed, next_version, file_path)?;
+    Ok(Some(output))
+}
+
+fn verify_toml_output(
+    output: &str,
+    targets: &BTreeMap<Vec<PathStep>, &str>,
+    next_version: &str,
+    file_path: &Path,
+) -> Result<()> {
+    let written: TomlValue = output.parse().with_context(|| {
+        format!(
+            "Failed to re-read updated TOML file `{}`.",
+            file_path.display()
+        )
+    })?;
+    for (path, selector_text) in targets {
+        let actual = toml_value_at_path(&written, path).and_then(TomlValue::as_str);
+        ensure_written_version(actual, path, selector_text, next_version, file_path)?;
+    }
+    Ok(())
 }
 
 fn resolve_toml_paths(
@@ -429,14 +1275,30 @@ fn resolve_toml_paths(
         current_paths = next_paths.into_iter().collect();
     }
 
-    if current_paths.is_empty() {
-        bail!(
-            "Selector `{selector_text}` matched no values in `{}`.",
+    Ok(current_paths)
+}
+
+fn ensure_not_workspace_inherited(
+    root: &TomlValue,
+    path: &[PathStep],
+    selector_text: &str,
+    file_path: &Path,
+) -> Result<()> {
+    let inherited = toml_value_at_path(root, path)
+        .and_then(TomlValue::as_table)
+        .and_then(|table| table.get("workspace"))
+        .and_then(TomlValue::as_bool)
+        .unwrap_or(false);
+    if inherited {
+        bail!(
+            "Selector `{selector_text}` in `{}` points at a value inherited from the workspace \
+             (`workspace = true`). Target `workspace.package.version` in the workspace root \
+             `Cargo.toml` instead.",
             file_path.display()
         );
     }
 
-    Ok(current_paths)
+    Ok(())
 }
 
 fn toml_value_at_path<'a>(root: &'a TomlValue, path: &[PathStep]) -> Option<&'a TomlValue> {
@@ -465,6 +1327,17 @@ fn set_toml_string_at_path(
     set_toml_string_in_item(root, path, next_version, selector_text, file_path)
 }
 
+fn json_type_name(value: &JsonValue) -> &'static str {
+    match value {
+        JsonValue::Null => "null",
+        JsonValue::Bool(_) => "boolean",
+        JsonValue::Number(_) => "number",
+        JsonValue::String(_) => "string",
+        JsonValue::Array(_) => "array",
+        JsonValue::Object(_) => "object",
+    }
+}
+
 fn set_toml_string_in_item(
     item: &mut Item,
     path: &[PathStep],
@@ -478,7 +1351,8 @@ fn set_toml_string_in_item(
                 set_toml_string_in_value(value, &[], next_version, selector_text, file_path)
             }
             _ => bail!(
-                "Selector `{selector_text}` matched a non-string TOML value in `{}`.",
+                "Selector `{selector_text}` matched a non-string TOML value ({}) in `{}`.",
+                item.type_name(),
                 file_path.display()
             ),
         };
@@ -545,7 +1419,7 @@ fn set_toml_string_in_table(
 ) -> Result<bool> {
     if path.is_empty() {
         bail!(
-            "Selector `{selector_text}` matched a non-string TOML value in `{}`.",
+            "Selector `{selector_text}` matched a non-string TOML value (table) in `{}`.",
             file_path.display()
         );
     }
@@ -577,7 +1451,8 @@ fn set_toml_string_in_value(
     if path.is_empty() {
         let Some(existing_value) = value.as_str() else {
             bail!(
-                "Selector `{selector_text}` matched a non-string TOML value in `{}`.",
+                "Selector `{selector_text}` matched a non-string TOML value ({}) in `{}`.",
+                value.type_name(),
                 file_path.display()
             );
         };
@@ -626,10 +1501,23 @@ fn set_toml_string_in_value(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::release_pr::ProcessRunner;
     use std::collections::BTreeMap;
     use std::fs;
     use tempfile::tempdir;
 
+    // Globs expand over `git ls-files`, so fixture files have to be tracked.
+    fn track_all(dir: &Path) {
+        for args in [&["init", "-q"][..], &["add", "-A"]] {
+            let status = std::process::Command::new("git")
+                .current_dir(dir)
+                .args(args)
+                .status()
+                .unwrap();
+            assert!(status.success(), "git {args:?} failed");
+        }
+    }
+
     #[test]
     fn updates_nested_json_key() {
         let temp_dir = tempdir().unwrap();
@@ -646,8 +1534,14 @@ mod tests {
             vec!["package.version".to_string()],
         );
 
-        let report =
-            apply_version_updates(temp_dir.path(), "1.1.0", &updates, &BTreeMap::new()).unwrap();
+        let report = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
 
         assert_eq!(report.changed_files, vec![PathBuf::from("package.json")]);
         let content = fs::read_to_string(file_path).unwrap();
@@ -670,8 +1564,14 @@ mod tests {
             vec!["packages[1].version".to_string()],
         );
 
-        let report =
-            apply_version_updates(temp_dir.path(), "9.9.9", &updates, &BTreeMap::new()).unwrap();
+        let report = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("9.9.9"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
 
         assert_eq!(report.changed_files, vec![PathBuf::from("package.json")]);
         let content = fs::read_to_string(file_path).unwrap();
@@ -695,8 +1595,14 @@ mod tests {
             vec!["package[name=brel].version".to_string()],
         );
 
-        let report =
-            apply_version_updates(temp_dir.path(), "7.7.7", &updates, &BTreeMap::new()).unwrap();
+        let report = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("7.7.7"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
 
         assert_eq!(report.changed_files, vec![PathBuf::from("package.json")]);
         let content = fs::read_to_string(file_path).unwrap();
@@ -704,6 +1610,450 @@ mod tests {
         assert!(content.contains("\"version\": \"2.0.0\""));
     }
 
+    #[test]
+    fn warns_when_selectors_resolve_to_overlapping_paths() {
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("package.json");
+        fs::write(
+            &file_path,
+            "{\n  \"packages\": [\n    {\"name\": \"a\", \"version\": \"1.0.0\"},\n    {\"name\": \"b\", \"version\": \"1.0.0\"}\n  ]\n}\n",
+        )
+        .unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert(
+            "package.json".to_string(),
+            vec![
+                "packages[0].version".to_string(),
+                "packages[name=a].version".to_string(),
+                "packages[1].version".to_string(),
+            ],
+        );
+
+        let report = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
+
+        assert_eq!(report.warnings.len(), 1);
+        assert!(
+            report.warnings[0].contains("`packages[0].version` and `packages[name=a].version`")
+        );
+        assert!(report.warnings[0].contains("`packages[0].version` in"));
+    }
+
+    #[test]
+    fn preview_reports_changes_without_writing() {
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("Cargo.toml");
+        let original = "[package]\nname = \"demo\"\nversion = \"1.0.0\"\n";
+        fs::write(&file_path, original).unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert(
+            "Cargo.toml".to_string(),
+            vec!["package.version".to_string()],
+        );
+
+        let report = preview_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(report.changed_files, vec![PathBuf::from("Cargo.toml")]);
+        assert_eq!(fs::read_to_string(file_path).unwrap(), original);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rejects_symlinked_file_outside_repo() {
+        let repo_dir = tempdir().unwrap();
+        let outside_dir = tempdir().unwrap();
+        let outside_file = outside_dir.path().join("version.json");
+        let original = "{\"version\":\"1.0.0\"}";
+        fs::write(&outside_file, original).unwrap();
+        std::os::unix::fs::symlink(&outside_file, repo_dir.path().join("version.json")).unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert("version.json".to_string(), vec!["version".to_string()]);
+
+        let err = apply_version_updates(
+            repo_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("outside the repository"));
+        assert_eq!(fs::read_to_string(outside_file).unwrap(), original);
+    }
+
+    #[test]
+    fn preview_includes_unified_diff_when_requested() {
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("Cargo.toml");
+        let original = "[package]\nname = \"demo\"\nversion = \"1.0.0\"\n";
+        fs::write(&file_path, original).unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert(
+            "Cargo.toml".to_string(),
+            vec!["package.version".to_string()],
+        );
+
+        let report = preview_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(report.diffs.len(), 1);
+        let diff = &report.diffs[0];
+        assert_eq!(diff.path, PathBuf::from("Cargo.toml"));
+        assert!(diff.unified.contains("--- a/Cargo.toml"));
+        assert!(diff.unified.contains("@@ -1,3 +1,3 @@"));
+        assert!(diff.unified.contains("-version = \"1.0.0\""));
+        assert!(diff.unified.contains("+version = \"1.1.0\""));
+        assert_eq!(fs::read_to_string(file_path).unwrap(), original);
+    }
+
+    #[test]
+    fn parallel_updates_match_sequential_order_and_errors() {
+        let temp_dir = tempdir().unwrap();
+        let mut updates = BTreeMap::new();
+        for index in 0..40 {
+            let relative_path = format!("pkg-{index:02}/package.json");
+            fs::create_dir_all(temp_dir.path().join(format!("pkg-{index:02}"))).unwrap();
+            let version = if index % 3 == 0 { "1.1.0" } else { "1.0.0" };
+            fs::write(
+                temp_dir.path().join(&relative_path),
+                format!("{{\"version\":\"{version}\",\"meta\":{{\"version\":\"{version}\"}}}}"),
+            )
+            .unwrap();
+            updates.insert(
+                relative_path,
+                vec!["version".to_string(), "meta.version".to_string()],
+            );
+        }
+
+        let report = preview_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+            true,
+        )
+        .unwrap();
+
+        let mut sequential_changed = Vec::new();
+        for (relative_path, selectors) in &updates {
+            let planned = plan_file_update(
+                temp_dir.path(),
+                relative_path,
+                selectors,
+                "1.1.0",
+                FormatOverrides::default(),
+                SelectorMatching::default(),
+            )
+            .unwrap();
+            if planned.output.is_some() {
+                sequential_changed.push(PathBuf::from(relative_path));
+            }
+        }
+        assert_eq!(report.changed_files, sequential_changed);
+        assert_eq!(
+            report
+                .diffs
+                .iter()
+                .map(|diff| diff.path.clone())
+                .collect::<Vec<_>>(),
+            sequential_changed
+        );
+
+        fs::write(temp_dir.path().join("pkg-05/package.json"), "{").unwrap();
+        fs::write(temp_dir.path().join("pkg-30/package.json"), "{").unwrap();
+        for _ in 0..5 {
+            let err = apply_version_updates(
+                temp_dir.path(),
+                VersionValues::bare("1.1.0"),
+                &updates,
+                &BTreeMap::new(),
+                SelectorMatching::default(),
+            )
+            .unwrap_err();
+            assert!(err.to_string().contains("pkg-05"), "{err:#}");
+        }
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("pkg-01/package.json")).unwrap(),
+            "{\"version\":\"1.0.0\",\"meta\":{\"version\":\"1.0.0\"}}"
+        );
+    }
+
+    #[test]
+    fn glob_keys_expand_to_matching_member_crates() {
+        let temp_dir = tempdir().unwrap();
+        for member in ["core", "cli"] {
+            fs::create_dir_all(temp_dir.path().join("crates").join(member)).unwrap();
+            fs::write(
+                temp_dir
+                    .path()
+                    .join("crates")
+                    .join(member)
+                    .join("Cargo.toml"),
+                format!("[package]\nname = \"{member}\"\nversion = \"1.0.0\"\n"),
+            )
+            .unwrap();
+        }
+        fs::create_dir_all(temp_dir.path().join("crates/core/nested")).unwrap();
+        fs::write(
+            temp_dir.path().join("crates/core/nested/Cargo.toml"),
+            "[package]\nversion = \"0.1.0\"\n",
+        )
+        .unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert(
+            "crates/*/Cargo.toml".to_string(),
+            vec!["package.version".to_string()],
+        );
+        track_all(temp_dir.path());
+        let expanded =
+            expand_version_update_globs(&mut ProcessRunner, temp_dir.path(), &updates, &[], false)
+                .unwrap();
+        assert_eq!(
+            expanded.keys().collect::<Vec<_>>(),
+            vec!["crates/cli/Cargo.toml", "crates/core/Cargo.toml"]
+        );
+
+        let report = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &expanded,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            report.changed_files,
+            vec![
+                PathBuf::from("crates/cli/Cargo.toml"),
+                PathBuf::from("crates/core/Cargo.toml")
+            ]
+        );
+        assert!(
+            fs::read_to_string(temp_dir.path().join("crates/cli/Cargo.toml"))
+                .unwrap()
+                .contains("version = \"1.1.0\"")
+        );
+        assert!(
+            fs::read_to_string(temp_dir.path().join("crates/core/nested/Cargo.toml"))
+                .unwrap()
+                .contains("version = \"0.1.0\"")
+        );
+    }
+
+    #[test]
+    fn glob_keys_without_matches_fail_unless_allowed() {
+        let temp_dir = tempdir().unwrap();
+        let mut updates = BTreeMap::new();
+        updates.insert(
+            "crates/*/Cargo.toml".to_string(),
+            vec!["package.version".to_string()],
+        );
+
+        track_all(temp_dir.path());
+        let err =
+            expand_version_update_globs(&mut ProcessRunner, temp_dir.path(), &updates, &[], false)
+                .unwrap_err();
+        assert!(err.to_string().contains("did not match any files"));
+
+        let expanded =
+            expand_version_update_globs(&mut ProcessRunner, temp_dir.path(), &updates, &[], true)
+                .unwrap();
+        assert!(expanded.is_empty());
+    }
+
+    #[test]
+    fn glob_keys_skip_ignored_files() {
+        let temp_dir = tempdir().unwrap();
+        for member in ["web", "scratch"] {
+            fs::create_dir_all(temp_dir.path().join("packages").join(member)).unwrap();
+            fs::write(
+                temp_dir
+                    .path()
+                    .join("packages")
+                    .join(member)
+                    .join("package.json"),
+                r#"{ "version": "1.0.0" }"#,
+            )
+            .unwrap();
+        }
+        fs::write(temp_dir.path().join(".gitignore"), "packages/scratch/\n").unwrap();
+        track_all(temp_dir.path());
+
+        let updates = BTreeMap::from([(
+            "packages/*/package.json".to_string(),
+            vec!["version".to_string()],
+        )]);
+        let expanded =
+            expand_version_update_globs(&mut ProcessRunner, temp_dir.path(), &updates, &[], false)
+                .unwrap();
+        assert_eq!(
+            expanded.keys().collect::<Vec<_>>(),
+            vec!["packages/web/package.json"]
+        );
+    }
+
+    #[test]
+    fn excluded_paths_are_dropped_from_glob_matches() {
+        let temp_dir = tempdir().unwrap();
+        for member in ["cli", "vendor"] {
+            fs::create_dir_all(temp_dir.path().join("crates").join(member)).unwrap();
+            fs::write(
+                temp_dir
+                    .path()
+                    .join("crates")
+                    .join(member)
+                    .join("Cargo.toml"),
+                "[package]\nversion = \"1.0.0\"\n",
+            )
+            .unwrap();
+        }
+        let mut updates = BTreeMap::new();
+        updates.insert(
+            "crates/*/Cargo.toml".to_string(),
+            vec!["package.version".to_string()],
+        );
+
+        track_all(temp_dir.path());
+        let expanded = expand_version_update_globs(
+            &mut ProcessRunner,
+            temp_dir.path(),
+            &updates,
+            &["crates/vendor/*".to_string()],
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            expanded.keys().collect::<Vec<_>>(),
+            vec!["crates/cli/Cargo.toml"]
+        );
+        apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &expanded,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("crates/vendor/Cargo.toml")).unwrap(),
+            "[package]\nversion = \"1.0.0\"\n"
+        );
+
+        let err = expand_version_update_globs(
+            &mut ProcessRunner,
+            temp_dir.path(),
+            &updates,
+            &["crates/*/Cargo.toml".to_string()],
+            false,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("outside `release_pr.exclude`"));
+    }
+
+    #[test]
+    fn read_back_mismatch_names_file_and_selector() {
+        let file_path = Path::new("package.json");
+        let mut targets = BTreeMap::new();
+        targets.insert(vec![PathStep::Key("version".to_string())], "version");
+        targets.insert(
+            vec![
+                PathStep::Key("packages".to_string()),
+                PathStep::Index(0),
+                PathStep::Key("version".to_string()),
+            ],
+            "packages[name=a].version",
+        );
+
+        verify_json_output(
+            r#"{"version":"1.1.0","packages":[{"version":"1.1.0"}]}"#,
+            &targets,
+            "1.1.0",
+            file_path,
+        )
+        .unwrap();
+
+        let err = verify_json_output(
+            r#"{"version":"1.1.0","packages":[{"version":"1.0.0"}]}"#,
+            &targets,
+            "1.1.0",
+            file_path,
+        )
+        .unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("Selector `packages[name=a].version` in `package.json`"));
+        assert!(message.contains("`packages[0].version`"));
+        assert!(message.contains("reads back as `1.0.0`"));
+
+        let err = verify_toml_output(
+            "[package]\nname = \"demo\"\n",
+            &BTreeMap::from([(
+                vec![
+                    PathStep::Key("package".to_string()),
+                    PathStep::Key("version".to_string()),
+                ],
+                "package.version",
+            )]),
+            "1.1.0",
+            Path::new("Cargo.toml"),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("a missing or non-string value"));
+    }
+
+    #[test]
+    fn apply_skips_diffs() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            "{\n  \"version\": \"1.0.0\"\n}\n",
+        )
+        .unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert("package.json".to_string(), vec!["version".to_string()]);
+
+        let report = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
+
+        assert_eq!(report.changed_files, vec![PathBuf::from("package.json")]);
+        assert!(report.diffs.is_empty());
+    }
+
     #[test]
     fn updates_nested_toml_key_without_reformatting() {
         let temp_dir = tempdir().unwrap();
@@ -720,8 +2070,14 @@ mod tests {
             vec!["package.version".to_string()],
         );
 
-        let report =
-            apply_version_updates(temp_dir.path(), "1.1.0", &updates, &BTreeMap::new()).unwrap();
+        let report = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
 
         assert_eq!(report.changed_files, vec![PathBuf::from("Cargo.toml")]);
         let content = fs::read_to_string(file_path).unwrap();
@@ -748,7 +2104,14 @@ mod tests {
         let mut overrides = BTreeMap::new();
         overrides.insert("Cargo.lock".to_string(), VersionFileFormat::Toml);
 
-        let report = apply_version_updates(temp_dir.path(), "0.3.0", &updates, &overrides).unwrap();
+        let report = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("0.3.0"),
+            &updates,
+            &overrides,
+            SelectorMatching::default(),
+        )
+        .unwrap();
 
         assert_eq!(report.changed_files, vec![PathBuf::from("Cargo.lock")]);
         let content = fs::read_to_string(file_path).unwrap();
@@ -756,6 +2119,439 @@ mod tests {
         assert!(content.contains("name = \"brel\"\nversion = \"0.3.0\""));
     }
 
+    #[test]
+    fn per_selector_format_mixes_toml_and_anchored_in_one_file() {
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("pyproject.toml");
+        fs::write(
+            &file_path,
+            "# pip install demo==<!-- brel:pin -->1.2.3<!-- /brel:pin -->\n\
+             [project]\nname = \"demo\"\nversion = \"1.2.3\"\n",
+        )
+        .unwrap();
+
+        let updates = BTreeMap::from([(
+            "pyproject.toml".to_string(),
+            vec!["project.version".to_string(), "pin".to_string()],
+        )]);
+        let selectors = BTreeMap::from([(
+            "pyproject.toml".to_string(),
+            BTreeMap::from([("pin".to_string(), VersionFileFormat::Anchored)]),
+        )]);
+        let overrides = FormatOverrides {
+            files: &BTreeMap::new(),
+            selectors: &selectors,
+        };
+
+        let report = apply_version_updates_with_formats(
+            temp_dir.path(),
+            VersionValues::bare("1.3.0"),
+            &updates,
+            overrides,
+            SelectorMatching::default(),
+        )
+        .unwrap();
+
+        assert_eq!(report.changed_files, vec![PathBuf::from("pyproject.toml")]);
+        assert_eq!(
+            fs::read_to_string(&file_path).unwrap(),
+            "# pip install demo==<!-- brel:pin -->1.3.0<!-- /brel:pin -->\n\
+             [project]\nname = \"demo\"\nversion = \"1.3.0\"\n"
+        );
+        assert_eq!(
+            read_version_from_file_with_formats(
+                temp_dir.path(),
+                "pyproject.toml",
+                "pin",
+                overrides
+            )
+            .unwrap(),
+            "1.3.0"
+        );
+    }
+
+    #[test]
+    fn anchored_format_replaces_text_between_markers() {
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("README.md");
+        fs::write(
+            &file_path,
+            "# Demo\n\nInstall <!-- brel:version -->1.2.3<!-- /brel:version --> today.\n\n\
+             ```\ndemo = \"<!-- brel:version -->1.2.3<!-- /brel:version -->\"\n```\n",
+        )
+        .unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert("README.md".to_string(), vec!["version".to_string()]);
+
+        let report = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.3.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
+
+        assert_eq!(report.changed_files, vec![PathBuf::from("README.md")]);
+        assert_eq!(
+            fs::read_to_string(file_path).unwrap(),
+            "# Demo\n\nInstall <!-- brel:version -->1.3.0<!-- /brel:version --> today.\n\n\
+             ```\ndemo = \"<!-- brel:version -->1.3.0<!-- /brel:version -->\"\n```\n"
+        );
+    }
+
+    #[test]
+    fn anchored_format_errors_on_missing_or_unbalanced_markers() {
+        let temp_dir = tempdir().unwrap();
+        let mut updates = BTreeMap::new();
+        updates.insert("README.md".to_string(), vec!["version".to_string()]);
+        let mut overrides = BTreeMap::new();
+        overrides.insert("README.md".to_string(), VersionFileFormat::Anchored);
+
+        for (content, expected) in [
+            ("# Demo\n", "No `<!-- brel:version -->`"),
+            (
+                "<!-- brel:version -->1.2.3\n",
+                "has no matching `<!-- /brel:version -->`",
+            ),
+            (
+                "1.2.3<!-- /brel:version -->\n",
+                "without a preceding `<!-- brel:version -->`",
+            ),
+            (
+                "<!-- brel:version --><!-- brel:version -->1.2.3<!-- /brel:version -->\n",
+                "is opened again",
+            ),
+        ] {
+            fs::write(temp_dir.path().join("README.md"), content).unwrap();
+            let err = apply_version_updates(
+                temp_dir.path(),
+                VersionValues::bare("1.3.0"),
+                &updates,
+                &overrides,
+                SelectorMatching::default(),
+            )
+            .unwrap_err();
+            assert!(
+                format!("{err:#}").contains(expected),
+                "unexpected error for {content:?}: {err:#}"
+            );
+        }
+    }
+
+    #[test]
+    fn reads_version_through_selectors_in_each_format() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{ "packages": [{ "name": "brel", "version": "1.4.0" }] }"#,
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"brel\"\nversion = \"0.9.1\"\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("README.md"),
+            "v<!-- brel:version -->2.0.0<!-- /brel:version --> and <!-- brel:version -->2.0.0<!-- /brel:version -->\n",
+        )
+        .unwrap();
+        let overrides = BTreeMap::new();
+
+        assert_eq!(
+            read_version_from_file(
+                temp_dir.path(),
+                "package.json",
+                "packages[name=brel].version",
+                &overrides
+            )
+            .unwrap(),
+            "1.4.0"
+        );
+        assert_eq!(
+            read_version_from_file(temp_dir.path(), "Cargo.toml", "package.version", &overrides)
+                .unwrap(),
+            "0.9.1"
+        );
+        assert_eq!(
+            read_version_from_file(temp_dir.path(), "README.md", "version", &overrides).unwrap(),
+            "2.0.0"
+        );
+    }
+
+    #[test]
+    fn reading_version_rejects_conflicting_or_non_string_matches() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{ "packages": [{ "name": "a", "version": "1.0.0" }, { "name": "a", "version": "2.0.0" }], "count": 3 }"#,
+        )
+        .unwrap();
+        let overrides = BTreeMap::new();
+
+        let err = read_version_from_file(
+            temp_dir.path(),
+            "package.json",
+            "packages[name=a].version",
+            &overrides,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("matched different versions"));
+
+        let err = read_version_from_file(temp_dir.path(), "package.json", "count", &overrides)
+            .unwrap_err();
+        assert!(err.to_string().contains("matched `number`"));
+
+        let err = read_version_from_file(temp_dir.path(), "missing.json", "version", &overrides)
+            .unwrap_err();
+        assert!(err.to_string().contains("was not found"));
+    }
+
+    #[test]
+    fn json_updates_keep_crlf_line_endings() {
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("package.json");
+        fs::write(
+            &file_path,
+            "{\r\n  \"name\": \"demo\",\r\n  \"version\": \"1.0.0\"\r\n}\r\n",
+        )
+        .unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert("package.json".to_string(), vec!["version".to_string()]);
+        apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&file_path).unwrap(),
+            "{\r\n  \"name\": \"demo\",\r\n  \"version\": \"1.1.0\"\r\n}\r\n"
+        );
+
+        fs::write(&file_path, "{\r\n  \"version\": \"1.1.0\"\r\n}").unwrap();
+        apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.2.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            fs::read_to_string(&file_path).unwrap(),
+            "{\r\n  \"version\": \"1.2.0\"\r\n}"
+        );
+    }
+
+    #[test]
+    fn toml_updates_keep_crlf_line_endings() {
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("Cargo.toml");
+        fs::write(
+            &file_path,
+            "[package]\r\nname = \"demo\"\r\nversion = \"1.0.0\"\r\n\r\n[dependencies]\r\nserde = \"1\"\r\n",
+        )
+        .unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert(
+            "Cargo.toml".to_string(),
+            vec!["package.version".to_string()],
+        );
+        apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&file_path).unwrap(),
+            "[package]\r\nname = \"demo\"\r\nversion = \"1.1.0\"\r\n\r\n[dependencies]\r\nserde = \"1\"\r\n"
+        );
+
+        fs::write(&file_path, "[package]\r\nversion = \"1.1.0\"").unwrap();
+        apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.2.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            fs::read_to_string(&file_path).unwrap(),
+            "[package]\r\nversion = \"1.2.0\""
+        );
+    }
+
+    #[test]
+    fn json_updates_keep_four_space_indentation() {
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("package.json");
+        fs::write(
+            &file_path,
+            "{\n    \"name\": \"demo\",\n    \"version\": \"1.0.0\",\n    \"tooling\": {\n        \"enabled\": true\n    }\n}\n",
+        )
+        .unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert("package.json".to_string(), vec!["version".to_string()]);
+        apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(file_path).unwrap(),
+            "{\n    \"name\": \"demo\",\n    \"version\": \"1.1.0\",\n    \"tooling\": {\n        \"enabled\": true\n    }\n}\n"
+        );
+    }
+
+    #[test]
+    fn json_updates_keep_original_key_order() {
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("package.json");
+        let original = r#"{
+  "name": "demo",
+  "version": "1.0.0",
+  "scripts": {
+    "test": "vitest",
+    "build": "tsc"
+  },
+  "dependencies": {
+    "zod": "^3.0.0",
+    "axios": "^1.0.0"
+  }
+}
+"#;
+        fs::write(&file_path, original).unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert("package.json".to_string(), vec!["version".to_string()]);
+        apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(file_path).unwrap(),
+            original.replace("\"1.0.0\"", "\"1.1.0\"")
+        );
+    }
+
+    #[test]
+    fn json_updates_keep_tabs_and_missing_trailing_newline() {
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("package.json");
+        fs::write(&file_path, "{\n\t\"version\": \"1.0.0\"\n}").unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert("package.json".to_string(), vec!["version".to_string()]);
+        apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(file_path).unwrap(),
+            "{\n\t\"version\": \"1.1.0\"\n}"
+        );
+    }
+
+    #[test]
+    fn json_updates_keep_single_line_files_compact() {
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("package.json");
+        fs::write(&file_path, "{\"version\":\"1.0.0\"}\n").unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert("package.json".to_string(), vec!["version".to_string()]);
+        apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(file_path).unwrap(),
+            "{\"version\":\"1.1.0\"}\n"
+        );
+    }
+
+    #[test]
+    fn glob_format_override_applies_to_matching_lock_file() {
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("Cargo.lock");
+        fs::write(
+            &file_path,
+            "version = 4\n\n[[package]]\nname = \"brel\"\nversion = \"0.2.0\"\n",
+        )
+        .unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert(
+            "Cargo.lock".to_string(),
+            vec!["package[name=brel].version".to_string()],
+        );
+        let mut overrides = BTreeMap::new();
+        overrides.insert("*.lock".to_string(), VersionFileFormat::Toml);
+
+        let report = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("0.3.0"),
+            &updates,
+            &overrides,
+            SelectorMatching::default(),
+        )
+        .unwrap();
+
+        assert_eq!(report.changed_files, vec![PathBuf::from("Cargo.lock")]);
+        let content = fs::read_to_string(file_path).unwrap();
+        assert!(content.contains("name = \"brel\"\nversion = \"0.3.0\""));
+    }
+
+    #[test]
+    fn exact_format_override_wins_over_glob() {
+        let mut overrides = BTreeMap::new();
+        overrides.insert("*.lock".to_string(), VersionFileFormat::Toml);
+        overrides.insert("deps.lock".to_string(), VersionFileFormat::Json);
+
+        assert_eq!(
+            resolve_format_override("deps.lock", &overrides),
+            Some(VersionFileFormat::Json)
+        );
+        assert_eq!(
+            resolve_format_override("nested/Cargo.lock", &overrides),
+            Some(VersionFileFormat::Toml)
+        );
+        assert_eq!(resolve_format_override("package.json", &overrides), None);
+    }
+
     #[test]
     fn fails_when_selector_matches_no_values() {
         let temp_dir = tempdir().unwrap();
@@ -765,11 +2561,158 @@ mod tests {
         let mut updates = BTreeMap::new();
         updates.insert("package.json".to_string(), vec!["version".to_string()]);
 
-        let err = apply_version_updates(temp_dir.path(), "1.1.0", &updates, &BTreeMap::new())
-            .unwrap_err();
+        let err = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap_err();
         assert!(err.to_string().contains("matched no values"));
     }
 
+    #[test]
+    fn allow_missing_selectors_skips_files_without_the_selector() {
+        let temp_dir = tempdir().unwrap();
+        let matching = SelectorMatching {
+            allow_missing: true,
+            ..Default::default()
+        };
+        fs::create_dir_all(temp_dir.path().join("packages/app")).unwrap();
+        fs::create_dir_all(temp_dir.path().join("packages/docs")).unwrap();
+        fs::write(
+            temp_dir.path().join("packages/app/package.json"),
+            "{ \"version\": \"1.0.0\" }\n",
+        )
+        .unwrap();
+        let docs = "{ \"name\": \"docs\" }\n";
+        fs::write(temp_dir.path().join("packages/docs/package.json"), docs).unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert(
+            "packages/app/package.json".to_string(),
+            vec!["version".to_string()],
+        );
+        updates.insert(
+            "packages/docs/package.json".to_string(),
+            vec!["version".to_string()],
+        );
+
+        let report = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            matching,
+        )
+        .unwrap();
+
+        assert_eq!(
+            report.changed_files,
+            vec![PathBuf::from("packages/app/package.json")]
+        );
+        assert_eq!(report.warnings.len(), 1);
+        assert!(report.warnings[0].contains("packages/docs/package.json"));
+        assert!(report.warnings[0].contains("allow_missing_selectors"));
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("packages/docs/package.json")).unwrap(),
+            docs
+        );
+    }
+
+    #[test]
+    fn tag_files_receive_the_rendered_tag_instead_of_the_version() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            "{ \"version\": \"1.0.0\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("VERSION.md"),
+            "Current release: <!-- brel:version -->v1.0.0<!-- /brel:version -->\n",
+        )
+        .unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert("package.json".to_string(), vec!["version".to_string()]);
+        updates.insert("VERSION.md".to_string(), vec!["version".to_string()]);
+        let tag_files = vec!["VERSION.md".to_string()];
+        let values = VersionValues {
+            version: "1.1.0",
+            tag: "v1.1.0",
+            tag_files: &tag_files,
+        };
+
+        apply_version_updates(
+            temp_dir.path(),
+            values,
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("package.json")).unwrap(),
+            "{\"version\":\"1.1.0\"}\n"
+        );
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("VERSION.md")).unwrap(),
+            "Current release: <!-- brel:version -->v1.1.0<!-- /brel:version -->\n"
+        );
+    }
+
+    #[test]
+    fn strict_single_match_rejects_filters_matching_several_values() {
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("package.json");
+        fs::write(
+            &file_path,
+            r#"{ "packages": [{ "name": "brel", "version": "1.0.0" }, { "name": "brel", "version": "1.0.0" }] }"#,
+        )
+        .unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert(
+            "package.json".to_string(),
+            vec!["packages[name=brel].version".to_string()],
+        );
+        let strict = SelectorMatching {
+            single_match: true,
+            ..Default::default()
+        };
+
+        let err = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            strict,
+        )
+        .unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("matched 2 values"));
+        assert!(message.contains("`packages[0].version`, `packages[1].version`"));
+
+        apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            fs::read_to_string(file_path)
+                .unwrap()
+                .matches("1.1.0")
+                .count(),
+            2
+        );
+    }
+
     #[test]
     fn fails_when_json_target_is_not_string() {
         let temp_dir = tempdir().unwrap();
@@ -779,10 +2722,96 @@ mod tests {
         let mut updates = BTreeMap::new();
         updates.insert("package.json".to_string(), vec!["version".to_string()]);
 
-        let err = apply_version_updates(temp_dir.path(), "1.1.0", &updates, &BTreeMap::new())
-            .unwrap_err();
+        let err = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap_err();
         let err_text = format!("{err:#}");
-        assert!(err_text.contains("non-string JSON value"));
+        assert!(err_text.contains("non-string JSON value (object)"));
+    }
+
+    #[test]
+    fn updates_deeply_nested_action_metadata_version() {
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("action.json");
+        fs::write(
+            &file_path,
+            r#"{"runs":{"using":"composite","steps":[{"uses":"acme/setup@v1","with":{"version":"1.0.0"}}]}}"#,
+        )
+        .unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert(
+            "action.json".to_string(),
+            vec!["runs.steps[0].with.version".to_string()],
+        );
+
+        apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap();
+
+        let updated: JsonValue =
+            serde_json::from_str(&fs::read_to_string(file_path).unwrap()).unwrap();
+        assert_eq!(updated["runs"]["steps"][0]["with"]["version"], "1.1.0");
+    }
+
+    #[test]
+    fn fails_clearly_when_nested_target_is_a_map_or_sequence() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("action.json"),
+            r#"{"runs":{"steps":[{"with":{"version":{"pin":"1.0.0"}}}]}}"#,
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("action.toml"),
+            "[[runs.steps]]\nwith = { version = [\"1.0.0\"] }\n\n[runs.steps.meta]\nname = \"x\"\n",
+        )
+        .unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert(
+            "action.json".to_string(),
+            vec!["runs.steps[0].with.version".to_string()],
+        );
+        let err = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap_err();
+        assert!(format!("{err:#}").contains("matched a non-string JSON value (object)"));
+
+        for (selector, kind) in [
+            ("runs.steps[0].with.version", "array"),
+            ("runs.steps[0].meta", "table"),
+        ] {
+            let mut updates = BTreeMap::new();
+            updates.insert("action.toml".to_string(), vec![selector.to_string()]);
+            let err = apply_version_updates(
+                temp_dir.path(),
+                VersionValues::bare("1.1.0"),
+                &updates,
+                &BTreeMap::new(),
+                SelectorMatching::default(),
+            )
+            .unwrap_err();
+            assert!(
+                format!("{err:#}").contains(&format!("matched a non-string TOML value ({kind})")),
+                "{err:#}"
+            );
+        }
     }
 
     #[test]
@@ -797,12 +2826,48 @@ mod tests {
             vec!["package.version".to_string()],
         );
 
-        let err = apply_version_updates(temp_dir.path(), "1.1.0", &updates, &BTreeMap::new())
-            .unwrap_err();
+        let err = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap_err();
         let err_text = format!("{err:#}");
         assert!(err_text.contains("non-string TOML value"));
     }
 
+    #[test]
+    fn fails_with_targeted_error_for_workspace_inherited_version() {
+        let temp_dir = tempdir().unwrap();
+        let crate_dir = temp_dir.path().join("crates/member");
+        fs::create_dir_all(&crate_dir).unwrap();
+        fs::write(
+            crate_dir.join("Cargo.toml"),
+            "[package]\nname = \"member\"\nversion.workspace = true\n",
+        )
+        .unwrap();
+
+        let mut updates = BTreeMap::new();
+        updates.insert(
+            "crates/member/Cargo.toml".to_string(),
+            vec!["package.version".to_string()],
+        );
+
+        let err = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap_err();
+        let err_text = format!("{err:#}");
+        assert!(err_text.contains("inherited from the workspace"));
+        assert!(err_text.contains("workspace.package.version"));
+    }
+
     #[test]
     fn fails_when_filter_is_applied_to_non_array() {
         let temp_dir = tempdir().unwrap();
@@ -819,8 +2884,14 @@ mod tests {
             vec!["package[name=brel].version".to_string()],
         );
 
-        let err = apply_version_updates(temp_dir.path(), "1.1.0", &updates, &BTreeMap::new())
-            .unwrap_err();
+        let err = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap_err();
         assert!(
             err.to_string()
                 .contains("expects segment `package` to be an array")
@@ -833,8 +2904,14 @@ mod tests {
         let mut updates = BTreeMap::new();
         updates.insert("missing.json".to_string(), vec!["version".to_string()]);
 
-        let err = apply_version_updates(temp_dir.path(), "1.1.0", &updates, &BTreeMap::new())
-            .unwrap_err();
+        let err = apply_version_updates(
+            temp_dir.path(),
+            VersionValues::bare("1.1.0"),
+            &updates,
+            &BTreeMap::new(),
+            SelectorMatching::default(),
+        )
+        .unwrap_err();
         assert!(err.to_string().contains("was not found"));
     }
 }
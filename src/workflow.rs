@@ -1,9 +1,11 @@
+use crate::config::Provider;
+use crate::release_pr::{self, CommandRunner};
 use anyhow::{Context, Result, bail};
+use serde_json::Value as JsonValue;
 use std::io::ErrorKind;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
-pub const MANAGED_MARKER: &str = "# managed-by: brel";
 pub const WORKFLOW_DIR: &str = ".github/workflows";
 
 pub fn resolve_workflow_path(workflow_file: &str) -> Result<PathBuf> {
@@ -21,11 +23,183 @@ pub fn resolve_workflow_path(workflow_file: &str) -> Result<PathBuf> {
     Ok(PathBuf::from(WORKFLOW_DIR).join(normalized))
 }
 
-pub fn is_managed(contents: &str) -> bool {
+/// Where `provider` expects the generated workflow; only GitHub has a workflow template.
+pub fn provider_workflow_path(provider: Provider, workflow_file: &str) -> Result<PathBuf> {
+    match provider {
+        Provider::Github => resolve_workflow_path(workflow_file),
+        Provider::Gitlab | Provider::Gitea => {
+            bail!("`brel init` cannot generate a workflow for provider `{provider}` yet.")
+        }
+    }
+}
+
+pub fn managed_header(marker: &str) -> String {
+    format!("# {marker}")
+}
+
+pub fn is_managed(contents: &str, marker: &str) -> bool {
+    let header = managed_header(marker);
     contents
         .lines()
         .next()
-        .is_some_and(|line| line.trim() == MANAGED_MARKER)
+        .is_some_and(|line| line.trim() == header)
+}
+
+pub fn detect_default_branch(
+    provider: Provider,
+    repo_root: &Path,
+    runner: &mut dyn CommandRunner,
+) -> Result<Option<String>> {
+    let detected = match provider {
+        Provider::Gitlab => detect_gitlab_default_branch(runner, repo_root),
+        Provider::Github | Provider::Gitea => None,
+    };
+
+    if let Some(branch) = detected {
+        return Ok(Some(branch));
+    }
+    if let Some(branch) = detect_origin_default_branch(repo_root)? {
+        return Ok(Some(branch));
+    }
+
+    // Fresh CI checkouts rarely have `origin/HEAD`, so ask GitHub when gh can authenticate.
+    if provider == Provider::Github {
+        return Ok(detect_github_default_branch(runner, repo_root));
+    }
+    Ok(None)
+}
+
+pub fn provider_mismatch_warning(
+    provider: Provider,
+    repo_root: &Path,
+    runner: &mut dyn CommandRunner,
+) -> Option<String> {
+    let output = runner
+        .run(
+            repo_root,
+            "git",
+            &[
+                "remote".to_string(),
+                "get-url".to_string(),
+                "origin".to_string(),
+            ],
+            &[],
+        )
+        .ok()?;
+    if output.status != 0 {
+        return None;
+    }
+
+    let host = remote_host(output.stdout.trim())?;
+    let inferred = infer_provider_from_host(&host)?;
+    if inferred == provider {
+        return None;
+    }
+    Some(format!(
+        "Configured provider `{provider}` does not match the `origin` remote host `{host}`, \
+         which looks like `{inferred}`."
+    ))
+}
+
+fn remote_host(remote: &str) -> Option<String> {
+    let web_url = release_pr::remote_url_to_web_url(remote)?;
+    let rest = web_url.strip_prefix("https://")?;
+    let (host, _) = rest.split_once('/')?;
+    Some(host.to_ascii_lowercase())
+}
+
+fn infer_provider_from_host(host: &str) -> Option<Provider> {
+    if host == "github.com" || host.ends_with(".github.com") {
+        return Some(Provider::Github);
+    }
+    if host == "gitlab.com" || host.starts_with("gitlab.") {
+        return Some(Provider::Gitlab);
+    }
+    if host == "gitea.com" || host == "codeberg.org" || host.starts_with("gitea.") {
+        return Some(Provider::Gitea);
+    }
+    None
+}
+
+fn detect_gitlab_default_branch(
+    runner: &mut dyn CommandRunner,
+    repo_root: &Path,
+) -> Option<String> {
+    let output = runner
+        .run(
+            repo_root,
+            "glab",
+            &[
+                "repo".to_string(),
+                "view".to_string(),
+                "--output".to_string(),
+                "json".to_string(),
+            ],
+            &[],
+        )
+        .ok()?;
+    if output.status != 0 {
+        return None;
+    }
+
+    parse_glab_default_branch(&output.stdout)
+}
+
+fn detect_github_default_branch(
+    runner: &mut dyn CommandRunner,
+    repo_root: &Path,
+) -> Option<String> {
+    if !runner.is_available("gh") {
+        return None;
+    }
+    let auth = runner
+        .run(
+            repo_root,
+            "gh",
+            &["auth".to_string(), "token".to_string()],
+            &[],
+        )
+        .ok()?;
+    if auth.status != 0 || auth.stdout.trim().is_empty() {
+        return None;
+    }
+
+    let output = runner
+        .run(
+            repo_root,
+            "gh",
+            &[
+                "repo".to_string(),
+                "view".to_string(),
+                "--json".to_string(),
+                "defaultBranchRef".to_string(),
+            ],
+            &[],
+        )
+        .ok()?;
+    if output.status != 0 {
+        return None;
+    }
+
+    parse_gh_default_branch(&output.stdout)
+}
+
+fn parse_gh_default_branch(stdout: &str) -> Option<String> {
+    let value: JsonValue = serde_json::from_str(stdout).ok()?;
+    let branch = value.get("defaultBranchRef")?.get("name")?.as_str()?.trim();
+    if branch.is_empty() {
+        return None;
+    }
+    Some(branch.to_string())
+}
+
+fn parse_glab_default_branch(stdout: &str) -> Option<String> {
+    let value: JsonValue = serde_json::from_str(stdout).ok()?;
+    let branch = value.get("default_branch")?.as_str()?.trim();
+    if branch.is_empty() {
+        return None;
+    }
+    Some(branch.to_string())
 }
 
 pub fn detect_origin_default_branch(repo_root: &Path) -> Result<Option<String>> {
@@ -63,13 +237,63 @@ pub fn detect_origin_default_branch(repo_root: &Path) -> Result<Option<String>>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::{ScriptedRunner, ok, status};
     use tempfile::tempdir;
 
+    #[test]
+    fn provider_mismatch_is_reported_for_github_remote() {
+        let temp_dir = tempdir().unwrap();
+        let mut runner = ScriptedRunner::new(vec![ok("git@github.com:acme/demo.git\n")]);
+
+        let warning =
+            provider_mismatch_warning(Provider::Gitlab, temp_dir.path(), &mut runner).unwrap();
+
+        assert_eq!(
+            warning,
+            "Configured provider `gitlab` does not match the `origin` remote host `github.com`, \
+             which looks like `github`."
+        );
+    }
+
+    #[test]
+    fn provider_mismatch_is_silent_when_hosts_agree_or_are_unknown() {
+        let temp_dir = tempdir().unwrap();
+        let mut runner = ScriptedRunner::new(vec![
+            ok("https://gitlab.com/acme/demo.git\n"),
+            ok("https://git.example.com/acme/demo.git\n"),
+            status(2),
+        ]);
+
+        assert!(
+            provider_mismatch_warning(Provider::Gitlab, temp_dir.path(), &mut runner).is_none()
+        );
+        assert!(
+            provider_mismatch_warning(Provider::Github, temp_dir.path(), &mut runner).is_none()
+        );
+        assert!(
+            provider_mismatch_warning(Provider::Github, temp_dir.path(), &mut runner).is_none()
+        );
+    }
+
     #[test]
     fn managed_marker_must_be_first_line() {
-        assert!(is_managed("# managed-by: brel\nname: Test"));
-        assert!(!is_managed("name: Test\n# managed-by: brel"));
-        assert!(!is_managed(""));
+        assert!(is_managed(
+            "# managed-by: brel\nname: Test",
+            "managed-by: brel"
+        ));
+        assert!(!is_managed(
+            "name: Test\n# managed-by: brel",
+            "managed-by: brel"
+        ));
+        assert!(!is_managed("", "managed-by: brel"));
+        assert!(is_managed(
+            "# managed-by: acme\nname: Test",
+            "managed-by: acme"
+        ));
+        assert!(!is_managed(
+            "# managed-by: brel\nname: Test",
+            "managed-by: acme"
+        ));
     }
 
     #[test]
@@ -81,10 +305,84 @@ mod tests {
         assert!(resolve_workflow_path("../release-pr.yml").is_err());
     }
 
+    #[test]
+    fn workflow_path_follows_provider_conventions() {
+        assert_eq!(
+            provider_workflow_path(Provider::Github, "release-pr.yml").unwrap(),
+            PathBuf::from(".github/workflows/release-pr.yml")
+        );
+        assert!(provider_workflow_path(Provider::Gitlab, "release-pr.yml").is_err());
+    }
+
     #[test]
     fn branch_detection_skips_when_origin_head_missing() {
         let temp_dir = tempdir().unwrap();
         let branch = detect_origin_default_branch(temp_dir.path()).unwrap();
         assert!(branch.is_none());
     }
+
+    #[test]
+    fn gitlab_detection_reads_default_branch_from_glab() {
+        let temp_dir = tempdir().unwrap();
+        let mut runner = ScriptedRunner::new(vec![ok(
+            r#"{"id": 42, "default_branch": "trunk", "path": "demo"}"#,
+        )]);
+
+        let branch = detect_default_branch(Provider::Gitlab, temp_dir.path(), &mut runner).unwrap();
+        assert_eq!(branch.as_deref(), Some("trunk"));
+        assert_eq!(runner.programs(), vec!["glab"]);
+    }
+
+    #[test]
+    fn gitlab_detection_falls_back_to_git_when_glab_fails() {
+        let temp_dir = tempdir().unwrap();
+        let mut runner = ScriptedRunner::new(vec![status(1)]);
+        let branch = detect_default_branch(Provider::Gitlab, temp_dir.path(), &mut runner).unwrap();
+        assert!(branch.is_none());
+
+        // No scripted response: `glab` fails to start.
+        let mut runner = ScriptedRunner::new(vec![]);
+        let branch = detect_default_branch(Provider::Gitlab, temp_dir.path(), &mut runner).unwrap();
+        assert!(branch.is_none());
+    }
+
+    #[test]
+    fn github_detection_skips_gh_when_it_is_not_installed() {
+        let temp_dir = tempdir().unwrap();
+        let mut runner = ScriptedRunner::new(vec![]);
+        runner.missing_programs.push("gh");
+        let branch = detect_default_branch(Provider::Github, temp_dir.path(), &mut runner).unwrap();
+        assert!(branch.is_none());
+        assert!(runner.calls.is_empty());
+    }
+
+    #[test]
+    fn github_detection_falls_back_to_gh_when_origin_head_is_unset() {
+        let temp_dir = tempdir().unwrap();
+        let mut runner = ScriptedRunner::new(vec![
+            ok("gho_token\n"),
+            ok(r#"{"defaultBranchRef":{"name":"trunk"}}"#),
+        ]);
+
+        let branch = detect_default_branch(Provider::Github, temp_dir.path(), &mut runner).unwrap();
+        assert_eq!(branch.as_deref(), Some("trunk"));
+        assert_eq!(runner.programs(), vec!["gh", "gh"]);
+    }
+
+    #[test]
+    fn github_detection_skips_gh_without_a_token() {
+        let temp_dir = tempdir().unwrap();
+        let mut runner = ScriptedRunner::new(vec![status(1)]);
+
+        let branch = detect_default_branch(Provider::Github, temp_dir.path(), &mut runner).unwrap();
+        assert!(branch.is_none());
+        assert_eq!(runner.programs(), vec!["gh"]);
+    }
+
+    #[test]
+    fn glab_output_without_default_branch_is_ignored() {
+        assert!(parse_glab_default_branch(r#"{"default_branch": ""}"#).is_none());
+        assert!(parse_glab_default_branch(r#"{"id": 1}"#).is_none());
+        assert!(parse_glab_default_branch("not json").is_none());
+    }
 }
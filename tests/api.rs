@@ -0,0 +1,128 @@
+use brel::BrelError;
+use semver::Version;
+use std::collections::BTreeMap;
+use std::fs;
+use std::process::Command as ProcessCommand;
+use tempfile::tempdir;
+
+#[test]
+fn computes_next_version_and_applies_updates_through_library_api() {
+    let temp_dir = tempdir().unwrap();
+    run_git(temp_dir.path(), &["init", "-q"]);
+    run_git(temp_dir.path(), &["config", "user.name", "Test User"]);
+    run_git(
+        temp_dir.path(),
+        &["config", "user.email", "test@example.com"],
+    );
+
+    fs::write(
+        temp_dir.path().join("brel.toml"),
+        r#"
+[release_pr.version_updates]
+"package.json" = ["version"]
+"#,
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("package.json"),
+        r#"{ "name": "demo", "version": "1.2.3" }"#,
+    )
+    .unwrap();
+    run_git(temp_dir.path(), &["add", "."]);
+    run_git(
+        temp_dir.path(),
+        &["commit", "-q", "-m", "chore: initial files"],
+    );
+    run_git(temp_dir.path(), &["tag", "v1.2.3"]);
+    fs::write(temp_dir.path().join("feature.txt"), "new").unwrap();
+    run_git(temp_dir.path(), &["add", "feature.txt"]);
+    run_git(
+        temp_dir.path(),
+        &["commit", "-q", "-m", "feat: add feature"],
+    );
+
+    let config = brel::load_config(None, temp_dir.path()).unwrap();
+    let next = brel::compute_next_version(temp_dir.path(), &config)
+        .unwrap()
+        .expect("expected a releasable version");
+
+    assert_eq!(next.version, Version::new(1, 3, 0));
+    assert_eq!(next.tag, "v1.3.0");
+    assert_eq!(next.previous_tag.as_deref(), Some("v1.2.3"));
+    assert_eq!(next.commit_count, 1);
+
+    let report = brel::apply_version_updates(
+        temp_dir.path(),
+        brel::VersionValues::from_config(&next.version.to_string(), &next.tag, &config.release_pr),
+        &config.release_pr.version_updates,
+        &config.release_pr.format_overrides,
+        brel::SelectorMatching::from_config(&config.release_pr),
+    )
+    .unwrap();
+    assert_eq!(report.changed_files, vec!["package.json".to_string()]);
+    let package: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(temp_dir.path().join("package.json")).unwrap())
+            .unwrap();
+    assert_eq!(package["version"], "1.3.0");
+}
+
+#[test]
+fn reports_typed_errors_for_missing_files_and_bad_config() {
+    let temp_dir = tempdir().unwrap();
+    let version_updates =
+        BTreeMap::from([("package.json".to_string(), vec!["version".to_string()])]);
+
+    let err = brel::apply_version_updates(
+        temp_dir.path(),
+        brel::VersionValues::bare("1.3.0"),
+        &version_updates,
+        &BTreeMap::new(),
+        brel::SelectorMatching::default(),
+    )
+    .unwrap_err();
+
+    assert!(matches!(&err, BrelError::MissingFile { path } if path == "package.json"));
+    assert_eq!(
+        err.to_string(),
+        "Configured version update file `package.json` was not found."
+    );
+
+    fs::write(
+        temp_dir.path().join("package.json"),
+        r#"{ "name": "demo", "version": "1.2.3" }"#,
+    )
+    .unwrap();
+    let malformed = BTreeMap::from([("package.json".to_string(), vec!["a..b".to_string()])]);
+    let err = brel::apply_version_updates(
+        temp_dir.path(),
+        brel::VersionValues::bare("1.3.0"),
+        &malformed,
+        &BTreeMap::new(),
+        brel::SelectorMatching::default(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, BrelError::Selector(_)));
+    assert!(err.to_string().contains("package.json"));
+
+    fs::write(
+        temp_dir.path().join("brel.toml"),
+        "provider = \"sourcehut\"\n",
+    )
+    .unwrap();
+    let err = brel::load_config(None, temp_dir.path()).unwrap_err();
+    assert!(matches!(err, BrelError::Config(_)));
+}
+
+fn run_git(cwd: &std::path::Path, args: &[&str]) {
+    let output = ProcessCommand::new("git")
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -36,6 +36,68 @@ fn release_pr_no_releasable_commits_exits_successfully() {
         ));
 }
 
+#[test]
+fn release_pr_warns_or_fails_when_version_updates_are_empty() {
+    let temp_dir = tempdir().unwrap();
+    init_git_repo(temp_dir.path());
+
+    fs::write(temp_dir.path().join("brel.toml"), "").unwrap();
+    run_git(temp_dir.path(), &["add", "brel.toml"]);
+    run_git(temp_dir.path(), &["commit", "-m", "feat: add config"]);
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    cmd.current_dir(temp_dir.path())
+        .arg("release-pr")
+        .assert()
+        .success()
+        .stdout(predicate::eq(""))
+        .stderr(predicate::str::contains(
+            "warning: Release 0.1.0 is due, but no `release_pr.version_updates` are configured",
+        ));
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    cmd.current_dir(temp_dir.path())
+        .args(["release-pr", "--strict"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Error: Release 0.1.0 is due, but no `release_pr.version_updates` are configured",
+        ));
+}
+
+#[test]
+fn release_pr_warns_when_managed_workflow_is_out_of_date() {
+    let temp_dir = tempdir().unwrap();
+    init_git_repo(temp_dir.path());
+
+    let workflow = temp_dir.path().join(".github/workflows/release-pr.yml");
+    fs::create_dir_all(workflow.parent().unwrap()).unwrap();
+    fs::write(&workflow, "# managed-by: brel\nname: old\n").unwrap();
+    fs::write(
+        temp_dir.path().join("brel.toml"),
+        r#"
+[release_pr.version_updates]
+"package.json" = ["version"]
+"#,
+    )
+    .unwrap();
+    run_git(temp_dir.path(), &["add", "."]);
+    run_git(temp_dir.path(), &["commit", "-m", "chore: add workflow"]);
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    cmd.current_dir(temp_dir.path())
+        .arg("release-pr")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Your brel workflow `.github/workflows/release-pr.yml` is out of date; run `brel init` to regenerate it.",
+        ));
+    assert_eq!(
+        fs::read_to_string(&workflow).unwrap(),
+        "# managed-by: brel\nname: old\n"
+    );
+}
+
 #[test]
 fn next_version_prints_semver_when_releasable_commits_exist() {
     let temp_dir = tempdir().unwrap();
@@ -53,6 +115,190 @@ fn next_version_prints_semver_when_releasable_commits_exist() {
         .stdout(predicate::eq("0.1.0\n"));
 }
 
+#[test]
+fn next_version_file_source_ignores_later_edits_that_keep_the_version() {
+    let temp_dir = tempdir().unwrap();
+    init_git_repo(temp_dir.path());
+    fs::write(
+        temp_dir.path().join("brel.toml"),
+        r#"
+[release_pr]
+version_source = "file:package.json:version"
+
+[release_pr.version_updates]
+"package.json" = ["version"]
+"#,
+    )
+    .unwrap();
+    let package = |dependency: &str| {
+        format!(r#"{{ "version": "1.2.0", "dependencies": {{ "left-pad": "{dependency}" }} }}"#)
+    };
+    fs::write(temp_dir.path().join("package.json"), package("^0.9.0")).unwrap();
+    run_git(temp_dir.path(), &["add", "."]);
+    run_git(temp_dir.path(), &["commit", "-m", "chore(release): v1.2.0"]);
+
+    fs::write(temp_dir.path().join("feature.txt"), "feat").unwrap();
+    run_git(temp_dir.path(), &["add", "feature.txt"]);
+    run_git(temp_dir.path(), &["commit", "-m", "feat: add feature"]);
+    fs::write(temp_dir.path().join("package.json"), package("^0.9.5")).unwrap();
+    run_git(temp_dir.path(), &["add", "package.json"]);
+    run_git(
+        temp_dir.path(),
+        &["commit", "-m", "chore(deps): bump left-pad"],
+    );
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    cmd.current_dir(temp_dir.path())
+        .arg("next-version")
+        .assert()
+        .success()
+        .stdout(predicate::eq("1.3.0\n"));
+}
+
+#[test]
+fn next_version_writes_bump_level_to_github_output() {
+    let temp_dir = tempdir().unwrap();
+    init_git_repo(temp_dir.path());
+
+    fs::write(temp_dir.path().join("feature.txt"), "feat").unwrap();
+    run_git(temp_dir.path(), &["add", "feature.txt"]);
+    run_git(temp_dir.path(), &["commit", "-m", "feat: add feature"]);
+    let github_output = temp_dir.path().join("github-output");
+    fs::write(&github_output, "version=0.1.0\n").unwrap();
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    cmd.current_dir(temp_dir.path())
+        .env("GITHUB_OUTPUT", &github_output)
+        .arg("next-version")
+        .assert()
+        .success()
+        .stdout(predicate::eq("0.1.0\n"));
+
+    assert_eq!(
+        fs::read_to_string(github_output).unwrap(),
+        "version=0.1.0\nbump=minor\n"
+    );
+}
+
+#[test]
+fn quiet_flag_hides_status_lines_but_keeps_version_output() {
+    let temp_dir = tempdir().unwrap();
+    init_git_repo(temp_dir.path());
+
+    Command::new(assert_cmd::cargo::cargo_bin!("brel"))
+        .current_dir(temp_dir.path())
+        .args(["--quiet", "init", "--yes"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+    assert!(
+        temp_dir
+            .path()
+            .join(".github/workflows/release-pr.yml")
+            .is_file()
+    );
+
+    Command::new(assert_cmd::cargo::cargo_bin!("brel"))
+        .current_dir(temp_dir.path())
+        .args(["release-pr", "--quiet"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+
+    fs::write(temp_dir.path().join("feature.txt"), "feat").unwrap();
+    run_git(temp_dir.path(), &["add", "feature.txt"]);
+    run_git(temp_dir.path(), &["commit", "-m", "feat: add feature"]);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("brel"))
+        .current_dir(temp_dir.path())
+        .args(["--quiet", "next-version"])
+        .assert()
+        .success()
+        .stdout(predicate::eq("0.1.0\n"));
+}
+
+#[test]
+fn next_version_verbose_reports_chosen_baseline_tag() {
+    let temp_dir = tempdir().unwrap();
+    init_git_repo(temp_dir.path());
+    run_git(temp_dir.path(), &["tag", "v0.1.0"]);
+
+    fs::write(temp_dir.path().join("feature.txt"), "feat").unwrap();
+    run_git(temp_dir.path(), &["add", "feature.txt"]);
+    run_git(temp_dir.path(), &["commit", "-m", "feat: add feature"]);
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    cmd.current_dir(temp_dir.path())
+        .args(["next-version", "--verbose"])
+        .assert()
+        .success()
+        .stdout(predicate::eq("0.2.0\n"))
+        .stderr(predicate::str::contains("verbose: baseline tag `v0.1.0`"))
+        .stderr(predicate::str::contains("bump level `minor`"));
+}
+
+#[test]
+fn next_version_previous_tag_flag_prints_baseline_tag() {
+    let temp_dir = tempdir().unwrap();
+    init_git_repo(temp_dir.path());
+    run_git(temp_dir.path(), &["tag", "v0.1.0"]);
+
+    fs::write(temp_dir.path().join("fix.txt"), "fix").unwrap();
+    run_git(temp_dir.path(), &["add", "fix.txt"]);
+    run_git(temp_dir.path(), &["commit", "-m", "fix: patch"]);
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    cmd.current_dir(temp_dir.path())
+        .args(["next-version", "--previous-tag"])
+        .assert()
+        .success()
+        .stdout(predicate::eq("v0.1.0\n"));
+}
+
+#[test]
+fn next_version_count_flag_appends_releasable_commit_count() {
+    let temp_dir = tempdir().unwrap();
+    init_git_repo(temp_dir.path());
+
+    fs::write(temp_dir.path().join("feature.txt"), "feat").unwrap();
+    run_git(temp_dir.path(), &["add", "feature.txt"]);
+    run_git(temp_dir.path(), &["commit", "-m", "feat: add feature"]);
+    fs::write(temp_dir.path().join("fix.txt"), "fix").unwrap();
+    run_git(temp_dir.path(), &["add", "fix.txt"]);
+    run_git(temp_dir.path(), &["commit", "-m", "fix: patch bug"]);
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    cmd.current_dir(temp_dir.path())
+        .args(["next-version", "--count"])
+        .assert()
+        .success()
+        .stdout(predicate::eq("0.1.0 (2 releasable commits)\n"));
+}
+
+#[test]
+fn next_version_respects_release_threshold() {
+    let temp_dir = tempdir().unwrap();
+    init_git_repo(temp_dir.path());
+
+    fs::write(
+        temp_dir.path().join("brel.toml"),
+        "[release_pr]\nrelease_threshold = \"minor\"\n",
+    )
+    .unwrap();
+    run_git(temp_dir.path(), &["add", "brel.toml"]);
+    run_git(temp_dir.path(), &["commit", "-m", "fix: patch"]);
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    cmd.current_dir(temp_dir.path())
+        .arg("next-version")
+        .assert()
+        .success()
+        .stdout(predicate::eq(""))
+        .stderr(predicate::str::contains(
+            "Only patch-level changes; below release threshold.",
+        ));
+}
+
 #[test]
 fn next_version_prints_nothing_when_no_releasable_commits_exist() {
     let temp_dir = tempdir().unwrap();
@@ -124,6 +370,32 @@ tag_template = "release-{version}"
         .stdout(predicate::eq("0.1.0\n"));
 }
 
+#[test]
+fn next_version_ignores_tags_unreachable_from_head() {
+    let temp_dir = tempdir().unwrap();
+    init_git_repo(temp_dir.path());
+    run_git(temp_dir.path(), &["tag", "v1.0.0"]);
+    run_git(temp_dir.path(), &["branch", "-M", "main"]);
+
+    run_git(temp_dir.path(), &["checkout", "-q", "-b", "experiment"]);
+    fs::write(temp_dir.path().join("experiment.txt"), "x").unwrap();
+    run_git(temp_dir.path(), &["add", "experiment.txt"]);
+    run_git(temp_dir.path(), &["commit", "-m", "feat!: experiment"]);
+    run_git(temp_dir.path(), &["tag", "v9.0.0"]);
+
+    run_git(temp_dir.path(), &["checkout", "-q", "main"]);
+    fs::write(temp_dir.path().join("fix.txt"), "fix").unwrap();
+    run_git(temp_dir.path(), &["add", "fix.txt"]);
+    run_git(temp_dir.path(), &["commit", "-m", "fix: patch bug"]);
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    cmd.current_dir(temp_dir.path())
+        .arg("next-version")
+        .assert()
+        .success()
+        .stdout(predicate::eq("1.0.1\n"));
+}
+
 #[test]
 fn init_without_config_creates_default_workflow() {
     let temp_dir = tempdir().unwrap();
@@ -158,6 +430,57 @@ fn init_without_config_creates_default_workflow() {
     assert!(!content.contains("pull_request:"));
 }
 
+#[test]
+fn init_from_subdirectory_writes_workflow_at_repository_toplevel() {
+    let temp_dir = tempdir().unwrap();
+    init_git_repo(temp_dir.path());
+    let subdir = temp_dir.path().join("packages/app");
+    fs::create_dir_all(&subdir).unwrap();
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    cmd.current_dir(&subdir)
+        .args(["init", "--yes"])
+        .assert()
+        .success();
+
+    assert!(
+        temp_dir
+            .path()
+            .join(".github/workflows/release-pr.yml")
+            .exists()
+    );
+    assert!(!subdir.join(".github").exists());
+}
+
+#[test]
+fn next_version_from_subdirectory_uses_the_nearest_config() {
+    let temp_dir = tempdir().unwrap();
+    init_git_repo(temp_dir.path());
+    let subdir = temp_dir.path().join("packages/web");
+    fs::create_dir_all(&subdir).unwrap();
+    fs::write(
+        subdir.join("brel.toml"),
+        r#"
+[release_pr.tagging]
+tag_template = "web-v{version}"
+"#,
+    )
+    .unwrap();
+    run_git(temp_dir.path(), &["add", "packages/web/brel.toml"]);
+    run_git(temp_dir.path(), &["commit", "-m", "chore: add web config"]);
+    run_git(temp_dir.path(), &["tag", "web-v1.2.0"]);
+    fs::write(subdir.join("feature.txt"), "feat").unwrap();
+    run_git(temp_dir.path(), &["add", "packages/web/feature.txt"]);
+    run_git(temp_dir.path(), &["commit", "-m", "feat: add feature"]);
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    cmd.current_dir(&subdir)
+        .arg("next-version")
+        .assert()
+        .success()
+        .stdout(predicate::eq("1.3.0\n"));
+}
+
 #[test]
 fn init_with_disabled_changelog_omits_git_cliff_step() {
     let temp_dir = tempdir().unwrap();
@@ -232,6 +555,27 @@ enabled = true
         ));
 }
 
+#[test]
+fn init_quiet_suppresses_tagging_notices() {
+    let temp_dir = tempdir().unwrap();
+    fs::write(
+        temp_dir.path().join("brel.toml"),
+        r#"
+[release_pr.tagging]
+enabled = true
+sign = true
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--quiet", "init", "--yes"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+}
+
 #[test]
 fn init_with_disabled_tagging_does_not_print_pat_notice() {
     let temp_dir = tempdir().unwrap();
@@ -288,6 +632,137 @@ fn dry_run_prints_diff_and_does_not_write() {
     assert_eq!(content, "# managed-by: brel\nname: old\n");
 }
 
+#[test]
+fn dry_run_json_reports_overwrite_as_a_single_object() {
+    let temp_dir = tempdir().unwrap();
+    let workflow = temp_dir.path().join(".github/workflows/release-pr.yml");
+    fs::create_dir_all(workflow.parent().unwrap()).unwrap();
+    fs::write(&workflow, "# managed-by: brel\nname: old\n").unwrap();
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    let output = cmd
+        .current_dir(temp_dir.path())
+        .args(["init", "--yes", "--dry-run", "--format", "json"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["path"], ".github/workflows/release-pr.yml");
+    assert_eq!(report["action"], "overwrite");
+    assert!(report["diff"].as_str().unwrap().contains("-name: old"));
+    assert_eq!(report.as_object().unwrap().len(), 3);
+    assert_eq!(
+        fs::read_to_string(workflow).unwrap(),
+        "# managed-by: brel\nname: old\n"
+    );
+}
+
+#[test]
+fn status_summarizes_a_releasable_repository() {
+    let temp_dir = tempdir().unwrap();
+    init_git_repo(temp_dir.path());
+
+    fs::write(
+        temp_dir.path().join("brel.toml"),
+        r#"
+[release_pr.version_updates]
+"package.json" = ["version"]
+"#,
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("package.json"),
+        r#"{ "name": "demo", "version": "0.1.0" }"#,
+    )
+    .unwrap();
+    run_git(temp_dir.path(), &["add", "."]);
+    run_git(temp_dir.path(), &["commit", "-m", "chore: add config"]);
+    run_git(temp_dir.path(), &["tag", "v0.1.0"]);
+    fs::write(temp_dir.path().join("feature.txt"), "feat").unwrap();
+    run_git(temp_dir.path(), &["add", "feature.txt"]);
+    run_git(temp_dir.path(), &["commit", "-m", "feat: add feature"]);
+    run_git(
+        temp_dir.path(),
+        &["commit", "--allow-empty", "-m", "fix: tidy up"],
+    );
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    cmd.current_dir(temp_dir.path())
+        .env_remove("GH_TOKEN")
+        .env_remove("GITHUB_TOKEN")
+        .arg("status")
+        .assert()
+        .success()
+        .stdout(predicate::eq(
+            "Latest release: v0.1.0\n\
+             Releasable commits: 2\n\
+             Next version: 0.2.0 (minor bump, tag v0.2.0)\n\
+             Release PR: unknown (no GitHub token)\n\
+             Version files:\n  \
+             would update `package.json`\n",
+        ));
+
+    assert_eq!(
+        fs::read_to_string(temp_dir.path().join("package.json")).unwrap(),
+        r#"{ "name": "demo", "version": "0.1.0" }"#
+    );
+}
+
+#[test]
+fn config_get_prints_resolved_value() {
+    let temp_dir = tempdir().unwrap();
+    fs::write(
+        temp_dir.path().join("brel.toml"),
+        "default_branch = \"trunk\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    cmd.current_dir(temp_dir.path())
+        .args(["config", "get", "default_branch"])
+        .assert()
+        .success()
+        .stdout(predicate::eq("trunk\n"));
+}
+
+#[test]
+fn config_get_applies_selected_profile() {
+    let temp_dir = tempdir().unwrap();
+    fs::write(
+        temp_dir.path().join("brel.toml"),
+        "default_branch = \"main\"\n\n[profile.staging]\ndefault_branch = \"staging\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    cmd.current_dir(temp_dir.path())
+        .args(["config", "get", "default_branch", "--profile", "staging"])
+        .assert()
+        .success()
+        .stdout(predicate::eq("staging\n"));
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    cmd.current_dir(temp_dir.path())
+        .args(["config", "get", "default_branch", "--profile", "production"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown profile `production`"));
+}
+
+#[test]
+fn config_get_fails_for_unknown_key() {
+    let temp_dir = tempdir().unwrap();
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("brel"));
+    cmd.current_dir(temp_dir.path())
+        .args(["config", "get", "release_pr.unknown"])
+        .assert()
+        .failure()
+        .stdout(predicate::eq(""))
+        .stderr(predicate::str::contains("Unsupported config key"));
+}
+
 fn init_git_repo(path: &std::path::Path) {
     run_git(path, &["init", "-q"]);
     run_git(path, &["config", "user.name", "Test User"]);